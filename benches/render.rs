@@ -0,0 +1,55 @@
+//! Cost of drawing one frame, with and without the static layer cache.
+//!
+//! ```text
+//! cargo bench --bench render
+//! ```
+
+use chrono::{TimeZone, Utc};
+use criterion::{criterion_group, criterion_main, Criterion};
+use dialup::clock::ClockHands;
+use dialup::theme::gmt_master::GmtMasterTheme;
+use dialup::theme::submariner::SubmarinerTheme;
+use dialup::theme::WatchTheme;
+use dialup::{WatchFace, WatchFaceState};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::widgets::StatefulWidget;
+
+fn face(theme: &dyn WatchTheme, frame: u32) -> WatchFace<'_> {
+    // A smooth second hand a tenth of a second further on each frame
+    let time = Utc.with_ymd_and_hms(2024, 3, 9, 10, 8, 37).unwrap()
+        + chrono::TimeDelta::milliseconds(100 * frame as i64);
+    WatchFace::new(theme).hands(ClockHands::at(&time, true)).stars(true).elapsed(frame as f64 / 10.0)
+}
+
+fn frames(c: &mut Criterion) {
+    let themes: [(&str, &dyn WatchTheme); 2] = [
+        ("submariner", &SubmarinerTheme),
+        ("gmt-master", &GmtMasterTheme::PEPSI),
+    ];
+    let area = Rect::new(0, 0, 160, 80);
+    for (name, theme) in themes {
+        let mut group = c.benchmark_group(name);
+        let mut buf = Buffer::empty(area);
+        let mut frame = 0;
+        group.bench_function("redrawn", |b| {
+            b.iter(|| {
+                frame += 1;
+                // A new state every frame: the static layers are drawn from scratch
+                let mut state = WatchFaceState::default();
+                StatefulWidget::render(face(theme, frame), area, &mut buf, &mut state);
+            })
+        });
+        let mut state = WatchFaceState::default();
+        group.bench_function("cached", |b| {
+            b.iter(|| {
+                frame += 1;
+                StatefulWidget::render(face(theme, frame), area, &mut buf, &mut state);
+            })
+        });
+        group.finish();
+    }
+}
+
+criterion_group!(benches, frames);
+criterion_main!(benches);
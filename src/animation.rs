@@ -0,0 +1,57 @@
+//! Animated GIFs of the face, one rendered frame at a time.
+//!
+//! Frames share one palette, the 216 colours of a 6 × 6 × 6 cube, so every
+//! frame quantises the same way and nothing flickers between them.
+
+use std::io::{self, Write};
+
+use gif::{Encoder, Frame, Repeat};
+use tiny_skia::Pixmap;
+
+use crate::graphics::cube_indices;
+
+/// An animated GIF being written, looping forever.
+pub struct Gif<W: Write> {
+    encoder: Encoder<W>,
+    side: u16,
+    fps: u32,
+    frames: u32,
+}
+
+impl<W: Write> Gif<W> {
+    /// Start a GIF of `side` pixels square frames shown `fps` times a second.
+    pub fn new(out: W, side: u16, fps: u32) -> io::Result<Self> {
+        let palette: Vec<u8> = (0..216u16)
+            .flat_map(|c| [c / 36, c / 6 % 6, c % 6].map(|level| (level * 51) as u8))
+            .collect();
+        let mut encoder = Encoder::new(out, side, side, &palette).map_err(io::Error::other)?;
+        encoder.set_repeat(Repeat::Infinite).map_err(io::Error::other)?;
+        Ok(Self { encoder, side, fps: fps.max(1), frames: 0 })
+    }
+
+    /// Add a frame, which must be the GIF's size.
+    pub fn frame(&mut self, pixmap: &Pixmap) -> io::Result<()> {
+        if (pixmap.width(), pixmap.height()) != (self.side as u32, self.side as u32) {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "frame size differs from the GIF's"));
+        }
+        // Delays are in hundredths of a second; spread the rounding so the
+        // frames keep to the clock overall
+        let at = |n: u32| (n as u64 * 100 + self.fps as u64 / 2) / self.fps as u64;
+        let delay = (at(self.frames + 1) - at(self.frames)) as u16;
+        let frame = Frame {
+            width: self.side,
+            height: self.side,
+            delay,
+            buffer: cube_indices(pixmap).into(),
+            ..Frame::default()
+        };
+        self.encoder.write_frame(&frame).map_err(io::Error::other)?;
+        self.frames += 1;
+        Ok(())
+    }
+
+    /// Finish the GIF and return the underlying writer.
+    pub fn into_inner(self) -> io::Result<W> {
+        self.encoder.into_inner()
+    }
+}
@@ -1,96 +1,343 @@
 use std::f64::consts::TAU;
-use std::time::Instant;
-use crate::theme::{WatchTheme, submariner::SubmarinerTheme};
-
-// 120 clicks per full rotation, matching real Submariner
-const CLICK_ANGLE: f64 = TAU / 120.0;
-
-pub struct Star {
-    pub x: f64,
-    pub y: f64,
-    pub size: f64,
-    pub phase: f64,
-    pub speed: f64,
-}
+use std::time::{Duration, Instant};
+use chrono::FixedOffset;
+use ratatui::layout::Rect;
+use dialup::capabilities::{Capabilities, ColorDepth};
+use dialup::clock::{ClockHands, Movement};
+use dialup::dive::{self, DiveAlarm};
+use dialup::graphics::Protocol;
+use dialup::marker::Marker;
+use dialup::scene::Scene;
+use dialup::theme::file::ThemeError;
+use dialup::theme::registry::ThemeRegistry;
+use dialup::theme::{BezelAction, BezelStyle, WatchTheme};
+use dialup::time_source::{self, TimeSource};
+use dialup::zone::Zone;
+use dialup::WatchFaceState;
 
-/// Simple LCG pseudo-random number generator (no external crate needed).
-struct SimpleRng {
-    state: u64,
-}
+use crate::cli::Options;
 
-impl SimpleRng {
-    fn new(seed: u64) -> Self {
-        Self { state: seed }
-    }
+// How long the bezel takes to settle after a click
+const BEZEL_TURN: Duration = Duration::from_millis(150);
 
-    fn next(&mut self) -> u64 {
-        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
-        self.state
-    }
+// How long the timer readout flashes once the dive duration is reached
+const ALARM_FLASH: Duration = Duration::from_secs(10);
 
-    /// Returns a f64 in [0.0, 1.0)
-    fn next_f64(&mut self) -> f64 {
-        (self.next() >> 11) as f64 / (1u64 << 53) as f64
-    }
+// How long a message stays on the status line
+const NOTICE_TIME: Duration = Duration::from_secs(3);
 
-    /// Returns a f64 in [lo, hi)
-    fn next_range(&mut self, lo: f64, hi: f64) -> f64 {
-        lo + self.next_f64() * (hi - lo)
-    }
-}
+// Longest wait between frames, for clocks that stand still
+const IDLE_FRAME: Duration = Duration::from_secs(1);
 
-fn generate_stars() -> Vec<Star> {
-    let mut rng = SimpleRng::new(0xDEAD_BEEF_CAFE);
-    let mut stars = Vec::new();
-    let bounds = 100.0;
-    let min_radius = 99.0; // must be outside the watch face
-
-    while stars.len() < 50 {
-        let x = rng.next_range(-bounds, bounds);
-        let y = rng.next_range(-bounds, bounds);
-        let dist = (x * x + y * y).sqrt();
-        if dist > min_radius {
-            stars.push(Star {
-                x,
-                y,
-                size: rng.next_range(0.3, 1.2),
-                phase: rng.next_range(0.0, TAU),
-                speed: rng.next_range(0.5, 2.0),
-            });
-        }
-    }
-    stars
+// Wake this long after a tick, so the frame lands on the new second
+const TICK_MARGIN: Duration = Duration::from_millis(2);
+
+/// A bezel turn, eased from `from` to `to` (radians, not wrapped to one turn).
+pub struct BezelTurn {
+    from: f64,
+    to: f64,
+    started: Instant,
 }
 
 pub struct App {
     pub running: bool,
-    pub theme: Box<dyn WatchTheme>,
-    pub bezel_offset: f64, // radians, added to all bezel element angles
-    pub stars: Vec<Star>,
+    pub themes: ThemeRegistry,
+    pub theme_index: usize,
+    pub picker: Option<usize>, // selected row while the theme picker is open
+    pub bezel_offset: f64, // radians, added to all bezel element angles; where the bezel settles
+    pub bezel_turn: Option<BezelTurn>, // the latest turn, animated towards `bezel_offset`
+    pub face: WatchFaceState,
     pub stars_enabled: bool,
     pub lume_mode: bool,
-    pub smooth_seconds: bool,
+    pub movement: Movement,
     pub start_time: Instant,
+    pub chrono_started: Option<Instant>, // set while the chronograph runs
+    pub chrono_banked: Duration, // time measured by earlier runs since the last reset
+    pub dive: DiveAlarm, // sounds when the bezel timer reaches the dive duration
+    pub alarm_until: Option<Instant>, // flash the readout until then
+    pub notice: Option<(String, Instant)>, // status line message, shown until then
+    pub clock: Box<dyn TimeSource>,
+    pub zone: Zone,
+    pub gmt_zone: Option<Zone>, // zone read by the GMT hand, None = same as `zone`
+    pub zones: Vec<Zone>, // faces in the world clock grid, empty = `zone` and UTC
+    pub world: bool, // show the world clock grid instead of a single face
+    pub marker: Option<Marker>, // None = from the capabilities, or the area size
+    pub color: Option<ColorDepth>, // None = from the capabilities
+    pub capabilities: Capabilities, // what the terminal was detected to show
+    pub graphics: Option<Protocol>, // draw faces as inline images, None = on the canvas
+    pub images: Vec<(Rect, Scene)>, // faces to show as images after this frame's text
+    pub shown_images: Vec<(Rect, Scene)>, // the images now on screen
+    pub fps: u32,
+    pub size: Option<(u16, u16)>, // fixed face area in cells, None = fill the terminal
 }
 
 impl App {
-    pub fn new() -> Self {
+    /// Create an app whose hands are driven by the given time source.
+    pub fn with_clock(themes: ThemeRegistry, clock: Box<dyn TimeSource>) -> Self {
+        assert!(!themes.is_empty(), "theme registry must contain at least one theme");
         Self {
             running: true,
-            theme: Box::new(SubmarinerTheme),
+            themes,
+            theme_index: 0,
+            picker: None,
             bezel_offset: 0.0,
-            stars: generate_stars(),
+            bezel_turn: None,
+            face: WatchFaceState::default(),
             stars_enabled: false,
             lume_mode: false,
-            smooth_seconds: false,
+            movement: Movement::Quartz,
             start_time: Instant::now(),
+            chrono_started: None,
+            chrono_banked: Duration::ZERO,
+            dive: DiveAlarm::default(),
+            alarm_until: None,
+            notice: None,
+            clock,
+            zone: Zone::Local,
+            gmt_zone: None,
+            zones: Vec::new(),
+            world: false,
+            marker: None,
+            color: None,
+            capabilities: Capabilities::default(),
+            graphics: None,
+            images: Vec::new(),
+            shown_images: Vec::new(),
+            fps: 10,
+            size: None,
+        }
+    }
+
+    /// Apply startup options from the command line. The theme is selected
+    /// separately with [`App::select_theme`], since an unknown name is an
+    /// error; select it first so the bezel lands on that theme's clicks.
+    pub fn apply_options(&mut self, options: &Options) {
+        if let Some(zone) = options.timezone {
+            self.zone = zone;
+        }
+        if options.gmt_zone.is_some() {
+            self.gmt_zone = options.gmt_zone;
+        }
+        if let Some(zones) = &options.zones {
+            self.zones = zones.clone();
+        }
+        if let Some(world) = options.world {
+            self.world = world;
+        }
+        if let Some(marker) = options.marker {
+            self.marker = marker;
+        }
+        if let Some(color) = options.color {
+            self.color = color;
+        }
+        if let Some(mode) = options.graphics {
+            self.graphics = mode.protocol(|name| std::env::var(name).ok());
+        }
+        if let Some(minutes) = options.bezel {
+            self.set_bezel(minutes / 60.0 * TAU);
+        }
+        if let Some(stars) = options.stars {
+            self.stars_enabled = stars;
+        }
+        if let Some(lume) = options.lume {
+            self.lume_mode = lume;
+        }
+        if let Some(movement) = options.movement {
+            self.movement = movement;
+        }
+        if options.dive.is_some() {
+            self.dive.minutes = options.dive;
+        }
+        if let Some(fps) = options.fps {
+            self.fps = fps;
+        }
+        if options.size.is_some() {
+            self.size = options.size;
+        }
+        if let Some(seed) = options.seed {
+            self.face = WatchFaceState::with_seed(seed);
+        }
+    }
+
+    pub fn theme(&self) -> &dyn WatchTheme {
+        self.themes.get(self.theme_index)
+    }
+
+    /// Switch to a theme by name, file stem or path to a theme file.
+    pub fn select_theme(&mut self, name: &str) -> Result<(), ThemeError> {
+        let index = self.themes.resolve(name)?;
+        self.set_theme(index);
+        Ok(())
+    }
+
+    /// Switch theme, moving the bezel onto the new bezel's clicks.
+    fn set_theme(&mut self, index: usize) {
+        self.theme_index = index;
+        self.set_bezel(self.bezel_offset);
+    }
+
+    /// Cycle through the registry (positive = next).
+    pub fn cycle_theme(&mut self, step: isize) {
+        self.set_theme(self.themes.step(self.theme_index, step));
+    }
+
+    pub fn open_picker(&mut self) {
+        self.picker = Some(self.theme_index);
+    }
+
+    pub fn close_picker(&mut self) {
+        self.picker = None;
+    }
+
+    /// Move the picker highlight, wrapping around the list.
+    pub fn move_picker(&mut self, step: isize) {
+        if let Some(row) = self.picker {
+            self.picker = Some(self.themes.step(row, step));
+        }
+    }
+
+    /// Apply the highlighted theme and close the picker.
+    pub fn confirm_picker(&mut self) {
+        if let Some(row) = self.picker.take() {
+            self.set_theme(row);
+        }
+    }
+
+    /// Hand positions for the current instant of the app's time source, in
+    /// the app's zone. The GMT hand reads the second zone when one is set.
+    pub fn hands(&self) -> ClockHands {
+        self.hands_in(self.zone)
+    }
+
+    /// Hand positions for the current instant in the given zone.
+    pub fn hands_in(&self, zone: Zone) -> ClockHands {
+        let now = self.clock.now();
+        let mut hands = zone.hands(now, self.movement);
+        hands.gmt_angle = self.gmt_zone.unwrap_or(zone).gmt_angle(now);
+        hands
+    }
+
+    /// Zones shown in the world clock grid.
+    pub fn world_zones(&self) -> Vec<Zone> {
+        if self.zones.is_empty() {
+            vec![self.zone, Zone::Fixed(FixedOffset::east_opt(0).unwrap())]
+        } else {
+            self.zones.clone()
         }
     }
 
+    pub fn toggle_world(&mut self) {
+        self.world = !self.world;
+    }
+
+    /// How long the face stays as drawn before anything on it moves. A
+    /// stepping movement only changes on its beat, so it sleeps until the
+    /// next one; a gliding hand, the stars or a running chronograph are drawn
+    /// at `fps`.
+    pub fn frame_interval(&self) -> Duration {
+        let interval = Duration::from_secs_f64(1.0 / self.fps as f64);
+        // Draw a turning bezel at 60 fps or better
+        if self.bezel_turning() {
+            return interval.min(Duration::from_millis(16));
+        }
+        let chronograph_running = self.chrono_started.is_some() && self.theme().has_chronograph();
+        let steps = match self.movement.steps_per_second() {
+            Some(steps) if !self.stars_enabled && !chronograph_running => steps,
+            _ => return interval,
+        };
+        let mut tick = time_source::until_next_step(&self.clock, steps)
+            .map_or(IDLE_FRAME, |wait| (wait + TICK_MARGIN).min(IDLE_FRAME));
+        if self.clock.rate() * steps > self.fps as f64 {
+            // Beats faster than `fps` are drawn no more often than that
+            tick = tick.max(interval);
+        }
+        if let Some((_, until)) = &self.notice {
+            // Clear the message when it runs out
+            if let Some(left) = until.checked_duration_since(Instant::now()) {
+                tick = tick.min(left + TICK_MARGIN);
+            }
+        }
+        if self.alarm_active() {
+            // The readout blinks on each half second
+            let into = self.elapsed_secs().fract() % 0.5;
+            return tick.min(Duration::from_secs_f64(0.5 - into));
+        }
+        tick
+    }
+
     pub fn elapsed_secs(&self) -> f64 {
         self.start_time.elapsed().as_secs_f64()
     }
 
+    /// Start or stop the chronograph, keeping the time measured so far.
+    pub fn toggle_chronograph(&mut self) {
+        match self.chrono_started.take() {
+            Some(started) => self.chrono_banked += started.elapsed(),
+            None => self.chrono_started = Some(Instant::now()),
+        }
+    }
+
+    /// Zero the chronograph. A running chronograph restarts from zero (flyback).
+    pub fn reset_chronograph(&mut self) {
+        self.chrono_banked = Duration::ZERO;
+        if self.chrono_started.is_some() {
+            self.chrono_started = Some(Instant::now());
+        }
+    }
+
+    /// Time measured by the chronograph, on the monotonic clock.
+    pub fn chronograph(&self) -> Duration {
+        self.chrono_banked + self.chrono_started.map_or(Duration::ZERO, |started| started.elapsed())
+    }
+
+    /// Align the bezel's zero pip with the minute hand, to time a dive from now.
+    pub fn set_bezel_to_now(&mut self) {
+        let spec = self.theme().bezel();
+        if spec.action == BezelAction::Fixed {
+            return;
+        }
+        self.turn_bezel(spec.turn_to_hand(self.bezel_offset, self.hands().minute_angle));
+        self.alarm_until = None;
+    }
+
+    /// Minutes elapsed on the bezel timer, from 0 up to 60.
+    pub fn dive_elapsed(&self) -> f64 {
+        dive::elapsed(self.hands().minute_angle, self.bezel_offset)
+    }
+
+    /// Whether the timer readout applies: only a dive bezel times minutes.
+    pub fn shows_dive_timer(&self) -> bool {
+        self.theme().bezel_style() == BezelStyle::Dive
+            && (self.bezel_offset != 0.0 || self.dive.minutes.is_some())
+    }
+
+    /// Advance the dive alarm. Returns true once when the timer passes the
+    /// configured duration as time goes by; moving the bezel never triggers it.
+    pub fn check_dive_alarm(&mut self) -> bool {
+        let alarm = self.dive.check(self.dive_elapsed()) && self.shows_dive_timer();
+        if alarm {
+            self.alarm_until = Some(Instant::now() + ALARM_FLASH);
+        }
+        alarm
+    }
+
+    /// Whether the dive alarm is still flashing.
+    pub fn alarm_active(&self) -> bool {
+        self.alarm_until.is_some_and(|until| Instant::now() < until)
+    }
+
+    /// Show a message on the status line for a few seconds.
+    pub fn notify(&mut self, text: impl Into<String>) {
+        self.notice = Some((text.into(), Instant::now() + NOTICE_TIME));
+    }
+
+    /// The status line message, while it is still shown.
+    pub fn notice(&self) -> Option<&str> {
+        self.notice.as_ref()
+            .filter(|(_, until)| Instant::now() < *until)
+            .map(|(text, _)| text.as_str())
+    }
+
     pub fn quit(&mut self) {
         self.running = false;
     }
@@ -103,14 +350,83 @@ impl App {
         self.lume_mode = !self.lume_mode;
     }
 
-    pub fn toggle_smooth(&mut self) {
-        self.smooth_seconds = !self.smooth_seconds;
+    /// Step through the movement presets.
+    pub fn cycle_movement(&mut self) {
+        self.movement = self.movement.next();
+    }
+
+    /// Step through the canvas markers, and back to picking one automatically.
+    pub fn cycle_marker(&mut self) {
+        self.marker = Marker::next(self.marker);
+        let text = match (self.marker, self.capabilities.marker()) {
+            (Some(marker), _) => format!("Marker: {}", marker),
+            (None, Some(marker)) => format!("Marker: auto ({})", marker),
+            (None, None) => "Marker: auto".to_string(),
+        };
+        self.notify(text);
+    }
+
+    /// The marker faces are drawn with, or `None` to pick from the area size.
+    pub fn marker(&self) -> Option<Marker> {
+        self.marker.or(self.capabilities.marker())
+    }
+
+    /// The colours the terminal is drawn in.
+    pub fn color_depth(&self) -> ColorDepth {
+        self.color.unwrap_or(self.capabilities.color)
     }
 
-    /// Rotate the bezel by the given number of clicks (positive = clockwise).
+    /// Turn the bezel by the given number of clicks (positive = clockwise),
+    /// as far as the theme's bezel allows.
     pub fn rotate_bezel(&mut self, clicks: i32) {
-        self.bezel_offset += clicks as f64 * CLICK_ANGLE;
+        let spec = self.theme().bezel();
+        let clicks = match spec.action {
+            BezelAction::Fixed => 0,
+            BezelAction::Unidirectional => clicks.min(0),
+            BezelAction::Bidirectional | BezelAction::FrictionLock => clicks,
+        };
+        if clicks != 0 {
+            self.turn_bezel(clicks as f64 * spec.click_angle());
+        }
+    }
+
+    /// Clicks for one keyboard step on the current theme's bezel.
+    pub fn bezel_step(&self) -> i32 {
+        self.theme().bezel().coarse_step()
+    }
+
+    /// Start an animated turn by `delta` radians from wherever the bezel is heading.
+    fn turn_bezel(&mut self, delta: f64) {
+        let from = self.bezel_angle();
+        let to = self.bezel_turn.as_ref().map_or(self.bezel_offset, |turn| turn.to) + delta;
+        self.bezel_turn = Some(BezelTurn { from, to, started: Instant::now() });
         // Normalize to [0, TAU)
-        self.bezel_offset = self.bezel_offset.rem_euclid(TAU);
+        self.bezel_offset = to.rem_euclid(TAU);
+        // Turning the bezel restarts the alarm check from the new reading
+        self.dive.restart(self.dive_elapsed());
+    }
+
+    /// Put the bezel at an angle straight away, snapped to the nearest click.
+    fn set_bezel(&mut self, angle: f64) {
+        self.bezel_offset = self.theme().bezel().snap(angle).rem_euclid(TAU);
+        self.bezel_turn = None;
+        self.dive.restart(self.dive_elapsed());
+    }
+
+    /// Where the bezel is drawn: eased part of the way through the latest turn.
+    pub fn bezel_angle(&self) -> f64 {
+        match &self.bezel_turn {
+            Some(turn) => {
+                let t = (turn.started.elapsed().as_secs_f64() / BEZEL_TURN.as_secs_f64()).min(1.0);
+                let eased = 1.0 - (1.0 - t).powi(3); // ease-out cubic
+                turn.from + (turn.to - turn.from) * eased
+            }
+            None => self.bezel_offset,
+        }
+    }
+
+    /// Whether a bezel turn is still being animated.
+    pub fn bezel_turning(&self) -> bool {
+        self.bezel_turn.as_ref().is_some_and(|turn| turn.started.elapsed() < BEZEL_TURN)
     }
 }
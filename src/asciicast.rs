@@ -0,0 +1,116 @@
+//! Recording terminal output as an [asciicast v2] file, as played back by
+//! asciinema.
+//!
+//! [`Cast`] writes the header and events; [`Recorder`] sits between the
+//! terminal backend and the real output, passing every byte through and
+//! recording what was written up to each flush as one event.
+//!
+//! [asciicast v2]: https://docs.asciinema.org/manual/asciicast/v2/
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+/// An asciicast v2 file being written.
+pub struct Cast<W: Write> {
+    out: W,
+}
+
+impl<W: Write> Cast<W> {
+    /// Start a recording of a `width` × `height` terminal by writing the
+    /// header. `timestamp` is the start in Unix seconds.
+    pub fn new(mut out: W, width: u16, height: u16, timestamp: i64, term: Option<&str>) -> io::Result<Self> {
+        let mut header = format!(r#"{{"version": 2, "width": {}, "height": {}, "timestamp": {}"#, width, height, timestamp);
+        if let Some(term) = term {
+            let _ = write!(header, r#", "env": {{"TERM": {}}}"#, json_string(term));
+        }
+        header.push('}');
+        writeln!(out, "{}", header)?;
+        Ok(Self { out })
+    }
+
+    /// Record output written `at` into the recording.
+    pub fn output(&mut self, at: Duration, data: &[u8]) -> io::Result<()> {
+        self.event(at, "o", &String::from_utf8_lossy(data))
+    }
+
+    /// Record the terminal being resized.
+    pub fn resize(&mut self, at: Duration, width: u16, height: u16) -> io::Result<()> {
+        self.event(at, "r", &format!("{}x{}", width, height))
+    }
+
+    fn event(&mut self, at: Duration, code: &str, data: &str) -> io::Result<()> {
+        writeln!(self.out, "[{:.6}, \"{}\", {}]", at.as_secs_f64(), code, json_string(data))
+    }
+
+    /// Flush and return the underlying writer.
+    pub fn into_inner(mut self) -> io::Result<W> {
+        self.out.flush()?;
+        Ok(self.out)
+    }
+}
+
+/// A writer that passes everything on to `inner` and records it to a cast,
+/// one event per flush, timed from when the recorder was created.
+pub struct Recorder<W: Write, C: Write> {
+    inner: W,
+    cast: Cast<C>,
+    started: Instant,
+    pending: Vec<u8>,
+}
+
+impl<W: Write, C: Write> Recorder<W, C> {
+    pub fn new(inner: W, cast: Cast<C>) -> Self {
+        Self { inner, cast, started: Instant::now(), pending: Vec::new() }
+    }
+
+    /// Record the terminal being resized.
+    pub fn resize(&mut self, width: u16, height: u16) -> io::Result<()> {
+        self.cast.resize(self.started.elapsed(), width, height)
+    }
+
+    /// The output and the cast, dropping anything written since the last
+    /// flush.
+    pub fn into_parts(self) -> (W, Cast<C>) {
+        (self.inner, self.cast)
+    }
+}
+
+impl<W: Write, C: Write> Write for Recorder<W, C> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.pending.extend_from_slice(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()?;
+        if !self.pending.is_empty() {
+            self.cast.output(self.started.elapsed(), &self.pending)?;
+            self.pending.clear();
+            self.cast.out.flush()?;
+        }
+        Ok(())
+    }
+}
+
+/// `s` as a JSON string literal.
+fn json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() + 2);
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 || c == '\u{7f}' => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
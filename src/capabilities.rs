@@ -0,0 +1,197 @@
+//! What the terminal can show, guessed from the environment: how many
+//! colours, and whether it has the glyphs for Braille.
+//!
+//! Nothing here talks to a terminal; detection reads variables through a
+//! function, so it can be checked with any environment.
+
+use std::fmt;
+use std::str::FromStr;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use ratatui::symbols::half_block;
+
+use crate::marker::Marker;
+use crate::scene;
+
+/// The 16 colours every colour terminal has, in palette order.
+const ANSI: [Color; 16] = [
+    Color::Black, Color::Red, Color::Green, Color::Yellow,
+    Color::Blue, Color::Magenta, Color::Cyan, Color::Gray,
+    Color::DarkGray, Color::LightRed, Color::LightGreen, Color::LightYellow,
+    Color::LightBlue, Color::LightMagenta, Color::LightCyan, Color::White,
+];
+
+/// Channel levels of the xterm 6 × 6 × 6 colour cube.
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// How many colours the terminal shows.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ColorDepth {
+    /// 24-bit colour: everything is drawn as it is.
+    #[default]
+    TrueColor,
+    /// The xterm 256-colour palette.
+    Indexed256,
+    /// The 16 ANSI colours.
+    Ansi16,
+    /// The terminal's own foreground and background only.
+    Mono,
+}
+
+/// What the terminal can show.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    pub color: ColorDepth,
+    /// The locale encodes text as UTF-8.
+    pub unicode: bool,
+    /// The font has Braille patterns, which the Linux console's do not.
+    pub braille: bool,
+}
+
+impl Default for Capabilities {
+    fn default() -> Self {
+        Self { color: ColorDepth::TrueColor, unicode: true, braille: true }
+    }
+}
+
+impl Capabilities {
+    /// Guess the terminal's capabilities from `NO_COLOR`, `COLORTERM`,
+    /// `TERM` and the locale, given a way to read environment variables.
+    ///
+    /// With no `TERM` at all, as on Windows, colours are left alone.
+    pub fn detect(var: impl Fn(&str) -> Option<String>) -> Self {
+        let set = |name: &str| var(name).filter(|v| !v.is_empty());
+        let term = set("TERM");
+        let colorterm = set("COLORTERM").unwrap_or_default().to_ascii_lowercase();
+        let console = term.as_deref() == Some("linux");
+
+        let color = if set("NO_COLOR").is_some() || term.as_deref() == Some("dumb") {
+            ColorDepth::Mono
+        } else if colorterm == "truecolor" || colorterm == "24bit" {
+            ColorDepth::TrueColor
+        } else if let Some(term) = &term {
+            if term.contains("direct") {
+                ColorDepth::TrueColor
+            } else if term.contains("256color") {
+                ColorDepth::Indexed256
+            } else {
+                ColorDepth::Ansi16
+            }
+        } else {
+            ColorDepth::TrueColor
+        };
+
+        // The first of these that is set decides, as for setlocale()
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"].iter().find_map(|name| set(name));
+        let unicode = locale.is_none_or(|l| {
+            let l = l.to_ascii_lowercase();
+            l.contains("utf-8") || l.contains("utf8")
+        });
+
+        Self { color, unicode, braille: unicode && !console }
+    }
+
+    /// The marker these capabilities need, or `None` when the face may pick
+    /// its own from the area size.
+    pub fn marker(&self) -> Option<Marker> {
+        if !self.unicode {
+            // The one canvas glyph nearly every legacy code page has
+            Some(Marker::Block)
+        } else if !self.braille {
+            Some(Marker::HalfBlock)
+        } else {
+            None
+        }
+    }
+}
+
+impl ColorDepth {
+    /// The nearest colour to `color` that can be shown at this depth.
+    pub fn reduce(self, color: Color) -> Color {
+        match (self, color) {
+            (_, Color::Reset) | (ColorDepth::TrueColor, _) => color,
+            (ColorDepth::Mono, _) => Color::Reset,
+            (ColorDepth::Indexed256, Color::Rgb(r, g, b)) => Color::Indexed(nearest_256(r, g, b)),
+            (ColorDepth::Ansi16, Color::Indexed(i)) if i < 16 => ANSI[i as usize],
+            (ColorDepth::Ansi16, Color::Rgb(..) | Color::Indexed(_)) => nearest_16(scene::rgb(color)),
+            _ => color,
+        }
+    }
+
+    /// Reduce every colour in `area` of `buf`. Without colour, a half block
+    /// whose other half was drawn in the background colour becomes a full
+    /// block, so neither half is lost.
+    pub fn reduce_buffer(self, buf: &mut Buffer, area: Rect) {
+        if self == ColorDepth::TrueColor {
+            return;
+        }
+        let area = area.intersection(buf.area);
+        for y in area.top()..area.bottom() {
+            for x in area.left()..area.right() {
+                let cell = &mut buf[(x, y)];
+                let half = matches!(cell.symbol().chars().next(), Some(half_block::UPPER | half_block::LOWER));
+                if self == ColorDepth::Mono && half && cell.bg != Color::Reset {
+                    cell.set_char(half_block::FULL);
+                }
+                cell.fg = self.reduce(cell.fg);
+                cell.bg = self.reduce(cell.bg);
+            }
+        }
+    }
+}
+
+/// The nearest colour in the cube or the grey ramp of the 256-colour palette.
+fn nearest_256(r: u8, g: u8, b: u8) -> u8 {
+    let level = |v: u8| {
+        (0..CUBE.len()).min_by_key(|&i| (CUBE[i] as i32 - v as i32).abs()).unwrap_or(0)
+    };
+    let (lr, lg, lb) = (level(r), level(g), level(b));
+    let cube = (CUBE[lr], CUBE[lg], CUBE[lb]);
+
+    // Greys run from 8 to 238 in steps of 10
+    let mean = (r as u32 + g as u32 + b as u32) / 3;
+    let step = (mean.saturating_sub(3) / 10).min(23) as u8;
+    let grey = 8 + 10 * step;
+
+    if distance((r, g, b), (grey, grey, grey)) < distance((r, g, b), cube) {
+        232 + step
+    } else {
+        16 + 36 * lr as u8 + 6 * lg as u8 + lb as u8
+    }
+}
+
+fn nearest_16(rgb: (u8, u8, u8)) -> Color {
+    ANSI.into_iter().min_by_key(|&c| distance(rgb, scene::rgb(c))).unwrap_or(Color::Reset)
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+impl fmt::Display for ColorDepth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorDepth::TrueColor => write!(f, "truecolor"),
+            ColorDepth::Indexed256 => write!(f, "256"),
+            ColorDepth::Ansi16 => write!(f, "16"),
+            ColorDepth::Mono => write!(f, "none"),
+        }
+    }
+}
+
+impl FromStr for ColorDepth {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "truecolor" | "24bit" => Ok(ColorDepth::TrueColor),
+            "256" => Ok(ColorDepth::Indexed256),
+            "16" => Ok(ColorDepth::Ansi16),
+            "none" | "mono" => Ok(ColorDepth::Mono),
+            _ => Err(()),
+        }
+    }
+}
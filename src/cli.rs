@@ -0,0 +1,449 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use dialup::capabilities::ColorDepth;
+use dialup::clock::Movement;
+use dialup::graphics;
+use dialup::marker::Marker;
+use dialup::zone::Zone;
+
+pub const USAGE: &str = "\
+Usage: dialup [OPTIONS]
+
+A terminal analogue wristwatch.
+
+Options:
+  -t, --theme <NAME>       Watch face theme, by name or path to a .toml theme file
+  -z, --timezone <ZONE>    Time zone: local, UTC, an offset like +05:30 or a name like Asia/Tokyo
+      --gmt-zone <ZONE>    Second time zone shown by the 24-hour GMT hand
+      --zones <LIST>       Comma-separated zones for the world clock grid
+      --world, --no-world  Start with or without the world clock grid
+      --marker <MARKER>    Canvas marker: auto, braille, quadrant, half-block, block, dot, bar
+      --color <DEPTH>      Colours to draw with: auto, truecolor, 256, 16 or none
+      --graphics <MODE>    Draw the face as an inline image: off, auto, kitty, sixel or iterm2
+      --bezel <MINUTES>    Initial bezel position in minutes (0-60, snapped to the bezel's clicks)
+      --dive <MINUTES>     Dive duration: ring the bell when the bezel timer reaches it
+      --stars, --no-stars  Show or hide the twinkling star background
+      --lume, --no-lume    Start in or out of lume mode
+      --movement <NAME>    How the hands move: quartz, mechanical-<VPH> (e.g. mechanical-28800),
+                           spring-drive, deadbeat or jumping-hour
+      --smooth, --quartz   Shorthand for --movement spring-drive / quartz
+      --fps <N>            Frame rate for a gliding movement, stars and the chronograph (1-120)
+      --size <COLSxROWS>   Draw the face in a fixed-size area instead of filling the terminal
+      --seed <N>           Seed for the star field
+      --record <FILE>      Record the session to an asciicast v2 file, for asciinema
+  -h, --help               Print this help
+  -V, --version            Print the version
+
+Commands:
+  export                   Draw the face to an image file instead of the terminal
+";
+
+pub const EXPORT_USAGE: &str = "\
+Usage: dialup export (--svg <FILE> | --png <FILE> | --gif <FILE>)... [OPTIONS]
+
+Draw the face at a given time to image files, or animate it over a stretch of time.
+
+Options:
+      --svg <FILE>         Write an SVG image to FILE, or to standard output for -
+      --png <FILE>         Write a PNG image to FILE, or to standard output for -
+      --gif <FILE>         Write an animated GIF to FILE, or to standard output for -
+      --duration <SECONDS> Simulated time the GIF covers, from --time (default: 10)
+      --time <TIME>        The time shown, or the GIF starts at: an RFC 3339 timestamp like 2024-03-09T10:08:37Z,
+                           or HH:MM[:SS] or YYYY-MM-DD HH:MM[:SS] in the face's time zone
+                           (default: now)
+      --width <PIXELS>     Width and height of the image (default: 512)
+  -h, --help               Print this help
+
+The face takes the same options as the watch itself, such as --theme, --timezone,
+--gmt-zone, --bezel, --lume, --stars, --movement and --seed; see 'dialup --help'.
+A GIF has --fps frames a second (default: 10, at most 50).
+";
+
+/// Startup options given on the command line or in the config file.
+/// `None` means "not given".
+#[derive(Debug, Default)]
+pub struct Options {
+    pub theme: Option<String>,
+    pub timezone: Option<Zone>,
+    pub gmt_zone: Option<Zone>,
+    pub zones: Option<Vec<Zone>>,
+    pub world: Option<bool>,
+    pub marker: Option<Option<Marker>>,
+    pub color: Option<Option<ColorDepth>>,
+    pub graphics: Option<graphics::Mode>,
+    pub bezel: Option<f64>, // minutes
+    pub dive: Option<f64>,
+    pub stars: Option<bool>,
+    pub lume: Option<bool>,
+    pub movement: Option<Movement>,
+    pub fps: Option<u32>,
+    pub size: Option<(u16, u16)>,
+    pub seed: Option<u64>,
+    pub record: Option<PathBuf>, // command line only
+}
+
+impl Options {
+    /// Fill every option not given here from `fallback`.
+    pub fn or(self, fallback: Options) -> Options {
+        Options {
+            theme: self.theme.or(fallback.theme),
+            timezone: self.timezone.or(fallback.timezone),
+            gmt_zone: self.gmt_zone.or(fallback.gmt_zone),
+            zones: self.zones.or(fallback.zones),
+            world: self.world.or(fallback.world),
+            marker: self.marker.or(fallback.marker),
+            color: self.color.or(fallback.color),
+            graphics: self.graphics.or(fallback.graphics),
+            bezel: self.bezel.or(fallback.bezel),
+            dive: self.dive.or(fallback.dive),
+            stars: self.stars.or(fallback.stars),
+            lume: self.lume.or(fallback.lume),
+            movement: self.movement.or(fallback.movement),
+            fps: self.fps.or(fallback.fps),
+            size: self.size.or(fallback.size),
+            seed: self.seed.or(fallback.seed),
+            record: self.record.or(fallback.record),
+        }
+    }
+}
+
+pub enum Command {
+    Run(Options),
+    Export(Export),
+    /// Print the given usage text
+    Help(&'static str),
+    Version,
+}
+
+/// What `dialup export` draws and where it writes it.
+#[derive(Debug)]
+pub struct Export {
+    pub options: Options,
+    /// The instant shown, `None` for now
+    pub time: Option<ExportTime>,
+    pub svg: Option<PathBuf>, // `-` for standard output
+    pub png: Option<PathBuf>,
+    pub gif: Option<PathBuf>,
+    pub duration: f64, // seconds of simulated time in the GIF
+    pub width: u32, // pixels; the image is square
+}
+
+/// The time given to `export --time`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ExportTime {
+    /// An RFC 3339 timestamp, which names its own offset
+    Instant(DateTime<Utc>),
+    /// A wall-clock time in the face's zone, on the given day or today
+    Wall(Option<NaiveDate>, NaiveTime),
+}
+
+#[derive(Debug)]
+pub enum CliError {
+    UnknownOption(String),
+    MissingValue(&'static str),
+    MissingOption(&'static str),
+    UnexpectedValue(&'static str),
+    InvalidValue { option: &'static str, value: String, reason: String },
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CliError::UnknownOption(arg) => write!(f, "unexpected argument '{}'", arg),
+            CliError::MissingValue(option) => write!(f, "a value is required for '{}'", option),
+            CliError::MissingOption(option) => write!(f, "{} is required", option),
+            CliError::UnexpectedValue(option) => write!(f, "'{}' does not take a value", option),
+            CliError::InvalidValue { option, value, reason } => {
+                write!(f, "invalid value '{}' for '{}': {}", value, option, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for CliError {}
+
+/// Parse the arguments that follow the program name.
+pub fn parse<I>(args: I) -> Result<Command, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut options = Options::default();
+    let mut args = args.into_iter().peekable();
+    if args.peek().is_some_and(|arg| arg == "export") {
+        args.next();
+        return parse_export(args);
+    }
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = split_flag(&arg);
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(Command::Help(USAGE)),
+            "-V" | "--version" => return Ok(Command::Version),
+            "--record" => options.record = Some(PathBuf::from(value("--record", inline, &mut args)?)),
+            _ => {
+                if !parse_option(&mut options, &flag, inline, &mut args)? {
+                    return Err(CliError::UnknownOption(arg));
+                }
+            }
+        }
+    }
+
+    Ok(Command::Run(options))
+}
+
+/// Parse the arguments that follow `export`.
+fn parse_export(mut args: impl Iterator<Item = String>) -> Result<Command, CliError> {
+    let mut options = Options::default();
+    let mut time = None;
+    let mut svg = None;
+    let mut png = None;
+    let mut gif = None;
+    let mut duration = 10.0;
+    let mut width = 512;
+
+    while let Some(arg) = args.next() {
+        let (flag, inline) = split_flag(&arg);
+        match flag.as_str() {
+            "-h" | "--help" => return Ok(Command::Help(EXPORT_USAGE)),
+            "--time" => {
+                let value = value("--time", inline, &mut args)?;
+                time = Some(parse_time(&value).map_err(|r| invalid("--time", value, r))?);
+            }
+            "--svg" => svg = Some(PathBuf::from(value("--svg", inline, &mut args)?)),
+            "--png" => png = Some(PathBuf::from(value("--png", inline, &mut args)?)),
+            "--gif" => gif = Some(PathBuf::from(value("--gif", inline, &mut args)?)),
+            "--duration" => {
+                let value = value("--duration", inline, &mut args)?;
+                let secs = value.parse().map_err(|_| invalid("--duration", value.clone(), DURATION_RANGE))?;
+                duration = check_duration(secs).map_err(|r| invalid("--duration", value, r))?;
+            }
+            "--width" => {
+                let value = value("--width", inline, &mut args)?;
+                let px = value.parse().map_err(|_| invalid("--width", value.clone(), WIDTH_RANGE))?;
+                width = check_width(px).map_err(|r| invalid("--width", value, r))?;
+            }
+            _ => {
+                if !parse_option(&mut options, &flag, inline, &mut args)? {
+                    return Err(CliError::UnknownOption(arg));
+                }
+            }
+        }
+    }
+
+    if svg.is_none() && png.is_none() && gif.is_none() {
+        return Err(CliError::MissingOption("one of '--svg <FILE>', '--png <FILE>' or '--gif <FILE>'"));
+    }
+    Ok(Command::Export(Export { options, time, svg, png, gif, duration, width }))
+}
+
+/// Split `--opt=value` into the flag and its value, so both it and
+/// `--opt value` are accepted.
+fn split_flag(arg: &str) -> (String, Option<String>) {
+    match arg.split_once('=') {
+        Some((flag, value)) if flag.starts_with("--") => (flag.to_string(), Some(value.to_string())),
+        _ => (arg.to_string(), None),
+    }
+}
+
+/// Parse one of the face's options into `options`. Returns false if `flag`
+/// is not one of them.
+fn parse_option(
+    options: &mut Options, flag: &str, inline: Option<String>, args: &mut impl Iterator<Item = String>,
+) -> Result<bool, CliError> {
+    let switch = |option: &'static str, value: bool| match inline {
+        Some(_) => Err(CliError::UnexpectedValue(option)),
+        None => Ok(Some(value)),
+    };
+
+    match flag {
+        "-t" | "--theme" => {
+            let value = value("--theme", inline, args)?;
+            options.theme = Some(parse_theme(&value).map_err(|r| invalid("--theme", value, r))?);
+        }
+        "-z" | "--timezone" => {
+            let value = value("--timezone", inline, args)?;
+            options.timezone = Some(parse_zone(&value).map_err(|r| invalid("--timezone", value, r))?);
+        }
+        "--gmt-zone" => {
+            let value = value("--gmt-zone", inline, args)?;
+            options.gmt_zone = Some(parse_zone(&value).map_err(|r| invalid("--gmt-zone", value, r))?);
+        }
+        "--zones" => {
+            let value = value("--zones", inline, args)?;
+            options.zones = Some(parse_zones(&value).map_err(|r| invalid("--zones", value, r))?);
+        }
+        "--world" => options.world = switch("--world", true)?,
+        "--no-world" => options.world = switch("--no-world", false)?,
+        "--marker" => {
+            let value = value("--marker", inline, args)?;
+            options.marker = Some(parse_marker(&value).map_err(|r| invalid("--marker", value, r))?);
+        }
+        "--color" | "--colour" => {
+            let value = value("--color", inline, args)?;
+            options.color = Some(parse_color(&value).map_err(|r| invalid("--color", value, r))?);
+        }
+        "--graphics" => {
+            let value = value("--graphics", inline, args)?;
+            options.graphics = Some(parse_graphics(&value).map_err(|r| invalid("--graphics", value, r))?);
+        }
+        "--bezel" => {
+            let value = value("--bezel", inline, args)?;
+            let minutes = value.parse().map_err(|_| invalid("--bezel", value.clone(), BEZEL_RANGE))?;
+            options.bezel = Some(check_bezel(minutes).map_err(|r| invalid("--bezel", value, r))?);
+        }
+        "--dive" => {
+            let value = value("--dive", inline, args)?;
+            let minutes = value.parse().map_err(|_| invalid("--dive", value.clone(), DIVE_RANGE))?;
+            options.dive = Some(check_dive(minutes).map_err(|r| invalid("--dive", value, r))?);
+        }
+        "--stars" => options.stars = switch("--stars", true)?,
+        "--no-stars" => options.stars = switch("--no-stars", false)?,
+        "--lume" => options.lume = switch("--lume", true)?,
+        "--no-lume" => options.lume = switch("--no-lume", false)?,
+        "--movement" => {
+            let value = value("--movement", inline, args)?;
+            options.movement = Some(parse_movement(&value).map_err(|r| invalid("--movement", value, r))?);
+        }
+        "--smooth" => options.movement = switch("--smooth", true)?.map(Movement::from),
+        "--quartz" => options.movement = switch("--quartz", false)?.map(Movement::from),
+        "--fps" => {
+            let value = value("--fps", inline, args)?;
+            let fps = value.parse().map_err(|_| invalid("--fps", value.clone(), FPS_RANGE))?;
+            options.fps = Some(check_fps(fps).map_err(|r| invalid("--fps", value, r))?);
+        }
+        "--size" => {
+            let value = value("--size", inline, args)?;
+            options.size = Some(parse_size(&value).map_err(|r| invalid("--size", value, r))?);
+        }
+        "--seed" => {
+            let value = value("--seed", inline, args)?;
+            options.seed = Some(parse_seed(&value).map_err(|r| invalid("--seed", value, r))?);
+        }
+        _ => return Ok(false),
+    }
+    Ok(true)
+}
+
+fn value(
+    option: &'static str, inline: Option<String>, args: &mut impl Iterator<Item = String>,
+) -> Result<String, CliError> {
+    inline.or_else(|| args.next()).ok_or(CliError::MissingValue(option))
+}
+
+fn invalid(option: &'static str, value: String, reason: impl Into<String>) -> CliError {
+    CliError::InvalidValue { option, value, reason: reason.into() }
+}
+
+// ── Value parsers, shared with the config file ──
+// Each returns the reason a value was rejected, for the caller to report.
+
+const BEZEL_RANGE: &str = "expected minutes from 0 up to 60";
+const DIVE_RANGE: &str = "expected minutes from 1 up to 60";
+const ZONE_EXPECTED: &str = "expected local, UTC, an offset like +05:30 or a name like Europe/London";
+const FPS_RANGE: &str = "expected a whole number from 1 to 120";
+const DURATION_RANGE: &str = "expected seconds, more than 0 and up to 600";
+const WIDTH_RANGE: &str = "expected a whole number of pixels from 16 to 16384";
+
+/// Theme names are resolved against the theme registry once it is loaded.
+pub fn parse_theme(s: &str) -> Result<String, String> {
+    match s.trim() {
+        "" => Err("expected a theme name or a path to a theme file".to_string()),
+        name => Ok(name.to_string()),
+    }
+}
+
+pub fn parse_zone(s: &str) -> Result<Zone, &'static str> {
+    s.parse().map_err(|_| ZONE_EXPECTED)
+}
+
+/// A comma-separated list of zones, e.g. `Europe/London,Asia/Tokyo`.
+pub fn parse_zones(s: &str) -> Result<Vec<Zone>, String> {
+    s.split(',')
+        .map(|zone| parse_zone(zone).map_err(|r| format!("'{}': {}", zone.trim(), r)))
+        .collect()
+}
+
+pub fn parse_movement(s: &str) -> Result<Movement, &'static str> {
+    s.parse().map_err(|_| "expected quartz, mechanical-<VPH> (3600 to 72000), spring-drive, deadbeat or jumping-hour")
+}
+
+/// `None` inside the result means "pick automatically".
+pub fn parse_marker(s: &str) -> Result<Option<Marker>, &'static str> {
+    if s.trim().eq_ignore_ascii_case("auto") {
+        return Ok(None);
+    }
+    s.parse().map(Some).map_err(|_| "expected auto, braille, quadrant, half-block, block, dot or bar")
+}
+
+/// `None` inside the result means "detect from the environment".
+pub fn parse_color(s: &str) -> Result<Option<ColorDepth>, &'static str> {
+    if s.trim().eq_ignore_ascii_case("auto") {
+        return Ok(None);
+    }
+    s.parse().map(Some).map_err(|_| "expected auto, truecolor, 256, 16 or none")
+}
+
+pub fn parse_graphics(s: &str) -> Result<graphics::Mode, &'static str> {
+    s.parse().map_err(|_| "expected off, auto, kitty, sixel or iterm2")
+}
+
+/// Bezel position in minutes; snapped to the theme's clicks once a theme is chosen.
+pub fn check_bezel(minutes: f64) -> Result<f64, &'static str> {
+    if (0.0..60.0).contains(&minutes) { Ok(minutes) } else { Err(BEZEL_RANGE) }
+}
+
+/// A dive must end within one turn of the bezel.
+pub fn check_dive(minutes: f64) -> Result<f64, &'static str> {
+    if (1.0..60.0).contains(&minutes) { Ok(minutes) } else { Err(DIVE_RANGE) }
+}
+
+pub fn check_fps(fps: u32) -> Result<u32, &'static str> {
+    if (1..=120).contains(&fps) { Ok(fps) } else { Err(FPS_RANGE) }
+}
+
+pub fn check_width(px: u32) -> Result<u32, &'static str> {
+    if (16..=16384).contains(&px) { Ok(px) } else { Err(WIDTH_RANGE) }
+}
+
+pub fn check_duration(secs: f64) -> Result<f64, &'static str> {
+    if secs > 0.0 && secs <= 600.0 { Ok(secs) } else { Err(DURATION_RANGE) }
+}
+
+/// An RFC 3339 timestamp, or a wall-clock time with an optional date.
+pub fn parse_time(s: &str) -> Result<ExportTime, &'static str> {
+    let s = s.trim();
+    if let Ok(instant) = DateTime::parse_from_rfc3339(s) {
+        return Ok(ExportTime::Instant(instant.to_utc()));
+    }
+    let time = |s: &str| {
+        NaiveTime::parse_from_str(s, "%H:%M:%S").or_else(|_| NaiveTime::parse_from_str(s, "%H:%M"))
+    };
+    if let Ok(time) = time(s) {
+        return Ok(ExportTime::Wall(None, time));
+    }
+    ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%dT%H:%M"].iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(s, format).ok())
+        .map(|wall| ExportTime::Wall(Some(wall.date()), wall.time()))
+        .ok_or("expected an RFC 3339 timestamp, HH:MM[:SS] or YYYY-MM-DD HH:MM[:SS]")
+}
+
+pub fn parse_size(s: &str) -> Result<(u16, u16), &'static str> {
+    let reason = "expected COLSxROWS, e.g. 80x40";
+    let (w, h) = s.to_ascii_lowercase().split_once('x')
+        .map(|(w, h)| (w.parse::<u16>(), h.parse::<u16>()))
+        .ok_or(reason)?;
+    match (w, h) {
+        (Ok(w), Ok(h)) if w > 0 && h > 0 => Ok((w, h)),
+        _ => Err(reason),
+    }
+}
+
+pub fn parse_seed(s: &str) -> Result<u64, &'static str> {
+    let parsed = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => u64::from_str_radix(&hex.replace('_', ""), 16).ok(),
+        None => s.replace('_', "").parse().ok(),
+    };
+    parsed.ok_or("expected a decimal or 0x-prefixed hex number")
+}
@@ -1,37 +1,163 @@
-use chrono::{Local, Timelike, Datelike};
+use chrono::{DateTime, Local, TimeZone, Timelike, Datelike};
 use std::f64::consts::TAU;
+use std::fmt;
+use std::str::FromStr;
 
+/// How the hands advance. Every hand is driven from the same gear train, so
+/// a stepping movement steps the minute and hour hands along with the seconds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Movement {
+    /// One step a second.
+    Quartz,
+    /// A balance wheel at the given vibrations per hour: 28,800 vph sweeps
+    /// the seconds in eight steps a second.
+    Mechanical(u32),
+    /// A continuous glide.
+    SpringDrive,
+    /// A mechanical movement whose seconds hand jumps once a second.
+    Deadbeat,
+    /// A mechanical movement whose hour hand jumps on the hour.
+    JumpingHour,
+}
+
+/// Beat of the mechanical movements that only name their complication.
+const BASE_VPH: u32 = 28_800;
+
+impl Movement {
+    /// The named movements, in the order the `m` key steps through them.
+    pub const PRESETS: [Movement; 8] = [
+        Movement::Quartz,
+        Movement::Mechanical(18_000),
+        Movement::Mechanical(21_600),
+        Movement::Mechanical(28_800),
+        Movement::Mechanical(36_000),
+        Movement::SpringDrive,
+        Movement::Deadbeat,
+        Movement::JumpingHour,
+    ];
+
+    /// How often the fastest hand moves, or `None` for a continuous glide.
+    pub fn steps_per_second(self) -> Option<f64> {
+        match self {
+            Movement::Quartz | Movement::Deadbeat => Some(1.0),
+            Movement::Mechanical(vph) => Some(vph as f64 / 3600.0),
+            Movement::SpringDrive => None,
+            Movement::JumpingHour => Some(BASE_VPH as f64 / 3600.0),
+        }
+    }
+
+    /// The next preset after this one, wrapping round.
+    pub fn next(self) -> Self {
+        let i = Self::PRESETS.iter().position(|&m| m == self).map_or(0, |i| i + 1);
+        Self::PRESETS[i % Self::PRESETS.len()]
+    }
+
+    /// Time shown by the seconds, minute and hour hands, given the time
+    /// `t` in seconds.
+    fn shown(self, t: f64) -> (f64, f64, f64) {
+        let step = |t: f64, per_second: f64| (t * per_second).floor() / per_second;
+        let base = BASE_VPH as f64 / 3600.0;
+        match self {
+            Movement::Quartz => (t.floor(), t.floor(), t.floor()),
+            Movement::Mechanical(vph) => {
+                let t = step(t, vph as f64 / 3600.0);
+                (t, t, t)
+            }
+            Movement::SpringDrive => (t, t, t),
+            Movement::Deadbeat => (t.floor(), step(t, base), step(t, base)),
+            Movement::JumpingHour => {
+                let t = step(t, base);
+                (t, t, (t / 3600.0).floor() * 3600.0)
+            }
+        }
+    }
+}
+
+/// `false` is the quartz tick, `true` the smooth sweep.
+impl From<bool> for Movement {
+    fn from(smooth: bool) -> Self {
+        if smooth { Movement::SpringDrive } else { Movement::Quartz }
+    }
+}
+
+impl fmt::Display for Movement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Movement::Quartz => write!(f, "quartz"),
+            Movement::Mechanical(vph) => write!(f, "mechanical-{}", vph),
+            Movement::SpringDrive => write!(f, "spring-drive"),
+            Movement::Deadbeat => write!(f, "deadbeat"),
+            Movement::JumpingHour => write!(f, "jumping-hour"),
+        }
+    }
+}
+
+/// Parses the names shown by [`Display`](fmt::Display). `mechanical-<vph>`
+/// takes any rate from 3,600 to 72,000 vph.
+impl FromStr for Movement {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim().to_ascii_lowercase();
+        match s.as_str() {
+            "quartz" => return Ok(Movement::Quartz),
+            "spring-drive" => return Ok(Movement::SpringDrive),
+            "deadbeat" => return Ok(Movement::Deadbeat),
+            "jumping-hour" => return Ok(Movement::JumpingHour),
+            _ => {}
+        }
+        let vph: u32 = s.strip_prefix("mechanical-").ok_or(())?.replace([',', '_'], "").parse().map_err(|_| ())?;
+        if (3_600..=72_000).contains(&vph) { Ok(Movement::Mechanical(vph)) } else { Err(()) }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
 pub struct ClockHands {
     pub hour_angle: f64,
     pub minute_angle: f64,
     pub second_angle: f64,
+    /// 24-hour hand: one revolution per day, by default in the same zone as the other hands.
+    pub gmt_angle: f64,
     pub date_day: u32,
 }
 
 impl ClockHands {
-    pub fn now(smooth: bool) -> Self {
-        let now = Local::now();
-        let h = (now.hour() % 12) as f64;
-        let m = now.minute() as f64;
-        let s = now.second() as f64;
+    /// Hand positions for the current local wall-clock time.
+    pub fn now(movement: impl Into<Movement>) -> Self {
+        Self::at(&Local::now(), movement)
+    }
+
+    /// Hand positions for the given time, read in its own time zone, as the
+    /// movement shows it. A `bool` picks the smooth sweep (`true`) or the
+    /// quartz tick.
+    pub fn at<Tz: TimeZone>(now: &DateTime<Tz>, movement: impl Into<Movement>) -> Self {
+        // Seconds into the 12-hour turn; a leap second is held at :59.999…
+        let nanos = now.nanosecond().min(999_999_999) as f64;
+        let t = ((now.hour() % 12) * 3600 + now.minute() * 60 + now.second()) as f64 + nanos / 1e9;
+        let (s, m, h) = movement.into().shown(t);
 
         // Angles in radians: 0 = 12 o'clock, increasing clockwise
-        let second_angle = if smooth {
-            let nanos = now.nanosecond() as f64;
-            (s + nanos / 1_000_000_000.0) * TAU / 60.0
-        } else {
-            s * TAU / 60.0
-        };
-        let minute_angle = (m + s / 60.0) * TAU / 60.0;
-        let hour_angle = (h + m / 60.0) * TAU / 12.0;
+        let second_angle = (s % 60.0) / 60.0 * TAU;
+        let minute_angle = (m % 3600.0) / 3600.0 * TAU;
+        let hour_angle = h / 43_200.0 * TAU;
 
         Self {
             hour_angle,
             minute_angle,
             second_angle,
+            gmt_angle: Self::gmt_angle_at(now),
             date_day: now.day(),
         }
     }
+
+    /// Angle of a 24-hour hand for the given time, read in its own time zone.
+    /// Use this to point the GMT hand at a second time zone.
+    pub fn gmt_angle_at<Tz: TimeZone>(time: &DateTime<Tz>) -> f64 {
+        let h = time.hour() as f64;
+        let m = time.minute() as f64;
+        let s = time.second() as f64;
+        (h + m / 60.0 + s / 3600.0) * TAU / 24.0
+    }
 }
 
 /// Convert a clock angle (0 = 12 o'clock, clockwise) and length to canvas (x, y).
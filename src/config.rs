@@ -0,0 +1,254 @@
+//! User configuration and saved session state.
+//!
+//! Defaults live in `$XDG_CONFIG_HOME/dialup/config.toml`; the theme, toggles
+//! and bezel position of the last session are written to
+//! `$XDG_STATE_HOME/dialup/state.toml` on quit and restored on launch.
+//! Command-line options override both.
+
+use std::f64::consts::TAU;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use toml::Spanned;
+
+use dialup::clock::Movement;
+use dialup::theme::{self, registry::ThemeRegistry};
+
+use crate::app::App;
+use crate::cli::{self, Options};
+
+const APP_DIR: &str = "dialup";
+
+/// The config file's contents, as written by the user.
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct ConfigFile {
+    theme: Option<Spanned<String>>,
+    timezone: Option<Spanned<String>>,
+    gmt_zone: Option<Spanned<String>>,
+    zones: Option<Vec<Spanned<String>>>,
+    world: Option<bool>,
+    marker: Option<Spanned<String>>,
+    color: Option<Spanned<String>>,
+    graphics: Option<Spanned<String>>,
+    bezel: Option<Spanned<f64>>,
+    dive: Option<Spanned<f64>>,
+    stars: Option<bool>,
+    lume: Option<bool>,
+    movement: Option<Spanned<String>>,
+    smooth: Option<bool>, // shorthand for a spring-drive or quartz movement
+    fps: Option<Spanned<u32>>,
+    size: Option<Spanned<String>>,
+    seed: Option<u64>,
+    restore_state: Option<bool>,
+}
+
+/// Defaults from the config file.
+pub struct Config {
+    pub options: Options,
+    /// Restore the previous session from the state file.
+    pub restore_state: bool,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self { options: Options::default(), restore_state: true }
+    }
+}
+
+/// The session state saved between runs.
+#[derive(Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+struct State {
+    theme: Option<String>,
+    world: Option<bool>,
+    stars: Option<bool>,
+    lume: Option<bool>,
+    movement: Option<String>,
+    smooth: Option<bool>, // written before movements had names; still read
+    bezel: Option<f64>,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, io::Error),
+    Parse(PathBuf, toml::de::Error),
+    Invalid { path: PathBuf, line: usize, key: &'static str, value: String, reason: String },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(path, e) => write!(f, "{}: {}", path.display(), e),
+            ConfigError::Parse(path, e) => write!(f, "{}: {}", path.display(), e.to_string().trim_end()),
+            ConfigError::Invalid { path, line, key, value, reason } => {
+                write!(f, "{}:{}: invalid value '{}' for '{}': {}", path.display(), line, value, key, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// `$XDG_CONFIG_HOME/dialup`, falling back to `~/.config/dialup` (`%APPDATA%\dialup` on Windows).
+pub fn config_dir() -> Option<PathBuf> {
+    base_dir("XDG_CONFIG_HOME", "APPDATA", ".config").map(|dir| dir.join(APP_DIR))
+}
+
+/// `$XDG_STATE_HOME/dialup`, falling back to `~/.local/state/dialup` (`%LOCALAPPDATA%\dialup` on Windows).
+pub fn state_dir() -> Option<PathBuf> {
+    base_dir("XDG_STATE_HOME", "LOCALAPPDATA", ".local/state").map(|dir| dir.join(APP_DIR))
+}
+
+/// `$XDG_DATA_HOME/dialup`, falling back to `~/.local/share/dialup` (`%APPDATA%\dialup` on Windows).
+pub fn data_dir() -> Option<PathBuf> {
+    base_dir("XDG_DATA_HOME", "APPDATA", ".local/share").map(|dir| dir.join(APP_DIR))
+}
+
+/// Directories searched for `*.toml` themes: `$DIALUP_THEME_PATH`, then
+/// `themes/` in the config and data directories.
+pub fn theme_search_path() -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = std::env::var_os("DIALUP_THEME_PATH")
+        .map(|paths| std::env::split_paths(&paths).collect())
+        .unwrap_or_default();
+    dirs.extend(config_dir().map(|dir| dir.join("themes")));
+    dirs.extend(data_dir().map(|dir| dir.join("themes")));
+    dirs
+}
+
+fn base_dir(xdg_var: &str, windows_var: &str, home_relative: &str) -> Option<PathBuf> {
+    let from_env = |var: &str| {
+        std::env::var_os(var).map(PathBuf::from).filter(|p| p.is_absolute())
+    };
+    if let Some(dir) = from_env(xdg_var) {
+        return Some(dir);
+    }
+    if cfg!(windows) {
+        return from_env(windows_var);
+    }
+    from_env("HOME").map(|home| home.join(home_relative))
+}
+
+/// Load `config.toml` from the config directory. A missing file is not an error.
+pub fn load() -> Result<Config, ConfigError> {
+    match config_dir() {
+        Some(dir) => load_from(&dir.join("config.toml")),
+        None => Ok(Config::default()),
+    }
+}
+
+pub fn load_from(path: &Path) -> Result<Config, ConfigError> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+        Err(e) => return Err(ConfigError::Io(path.to_path_buf(), e)),
+    };
+    let file: ConfigFile = toml::from_str(&text)
+        .map_err(|e| ConfigError::Parse(path.to_path_buf(), e))?;
+
+    // Report a rejected value with the line it appears on
+    let invalid = |key: &'static str, span: std::ops::Range<usize>, reason: String| {
+        ConfigError::Invalid {
+            path: path.to_path_buf(),
+            line: text[..span.start].matches('\n').count() + 1,
+            key,
+            value: text[span].trim_matches('"').to_string(),
+            reason,
+        }
+    };
+    let mut options = Options::default();
+    if let Some(v) = &file.theme {
+        options.theme = Some(cli::parse_theme(v.get_ref()).map_err(|r| invalid("theme", v.span(), r))?);
+    }
+    if let Some(v) = &file.timezone {
+        options.timezone = Some(cli::parse_zone(v.get_ref()).map_err(|r| invalid("timezone", v.span(), r.into()))?);
+    }
+    if let Some(v) = &file.gmt_zone {
+        options.gmt_zone = Some(cli::parse_zone(v.get_ref()).map_err(|r| invalid("gmt-zone", v.span(), r.into()))?);
+    }
+    if let Some(list) = &file.zones {
+        let mut zones = Vec::new();
+        for v in list {
+            zones.push(cli::parse_zone(v.get_ref()).map_err(|r| invalid("zones", v.span(), r.into()))?);
+        }
+        options.zones = Some(zones);
+    }
+    if let Some(v) = &file.marker {
+        options.marker = Some(cli::parse_marker(v.get_ref()).map_err(|r| invalid("marker", v.span(), r.into()))?);
+    }
+    if let Some(v) = &file.color {
+        options.color = Some(cli::parse_color(v.get_ref()).map_err(|r| invalid("color", v.span(), r.into()))?);
+    }
+    if let Some(v) = &file.graphics {
+        options.graphics = Some(cli::parse_graphics(v.get_ref()).map_err(|r| invalid("graphics", v.span(), r.into()))?);
+    }
+    if let Some(v) = &file.size {
+        options.size = Some(cli::parse_size(v.get_ref()).map_err(|r| invalid("size", v.span(), r.into()))?);
+    }
+    if let Some(v) = &file.bezel {
+        options.bezel = Some(cli::check_bezel(*v.get_ref()).map_err(|r| invalid("bezel", v.span(), r.into()))?);
+    }
+    if let Some(v) = &file.dive {
+        options.dive = Some(cli::check_dive(*v.get_ref()).map_err(|r| invalid("dive", v.span(), r.into()))?);
+    }
+    if let Some(v) = &file.movement {
+        options.movement = Some(cli::parse_movement(v.get_ref()).map_err(|r| invalid("movement", v.span(), r.into()))?);
+    }
+    if let Some(v) = &file.fps {
+        options.fps = Some(cli::check_fps(*v.get_ref()).map_err(|r| invalid("fps", v.span(), r.into()))?);
+    }
+    options.world = file.world;
+    options.stars = file.stars;
+    options.lume = file.lume;
+    options.movement = options.movement.or(file.smooth.map(Movement::from));
+    options.seed = file.seed;
+
+    Ok(Config { options, restore_state: file.restore_state.unwrap_or(true) })
+}
+
+fn state_path() -> Option<PathBuf> {
+    state_dir().map(|dir| dir.join("state.toml"))
+}
+
+/// The previous session's settings. A missing or unreadable state file
+/// simply restores nothing — it is rewritten on the next quit.
+pub fn load_state(themes: &ThemeRegistry) -> Options {
+    let state: State = state_path()
+        .and_then(|path| fs::read_to_string(path).ok())
+        .and_then(|text| toml::from_str(&text).ok())
+        .unwrap_or_default();
+
+    Options {
+        theme: state.theme.filter(|name| themes.position(name).is_some()),
+        world: state.world,
+        stars: state.stars,
+        lume: state.lume,
+        movement: state.movement.and_then(|name| name.parse().ok())
+            .or(state.smooth.map(Movement::from)),
+        bezel: state.bezel.and_then(|minutes| cli::check_bezel(minutes).ok()),
+        ..Options::default()
+    }
+}
+
+/// Write the app's theme, toggles and bezel position to the state file.
+pub fn save_state(app: &App) -> io::Result<()> {
+    let Some(path) = state_path() else { return Ok(()) };
+    let state = State {
+        theme: Some(theme::normalize_name(app.theme().name())),
+        world: Some(app.world),
+        stars: Some(app.stars_enabled),
+        lume: Some(app.lume_mode),
+        movement: Some(app.movement.to_string()),
+        smooth: None,
+        // Stored in minutes, to a hundredth; restoring snaps it back onto a click
+        bezel: Some((app.bezel_offset / TAU * 6000.0).round() / 100.0 % 60.0),
+    };
+    let text = toml::to_string(&state).map_err(io::Error::other)?;
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, text)
+}
@@ -0,0 +1,39 @@
+//! The rotating bezel as a dive timer: the minute hand read against the
+//! bezel's zero pip, and an alarm for when a planned dive time is up.
+//!
+//! Angles are clock angles in radians, as the bezel offset and the hands use.
+
+use std::f64::consts::TAU;
+
+/// Minutes elapsed on the timer with the bezel at `offset`: the minute hand
+/// read against the pip, from 0 up to 60.
+pub fn elapsed(minute_angle: f64, offset: f64) -> f64 {
+    // The epsilon keeps a pip set exactly on the hand at 0 rather than a hair under 60
+    (minute_angle - offset + 1e-9).rem_euclid(TAU) / TAU * 60.0
+}
+
+/// Sounds once when the timer reading passes a dive duration.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DiveAlarm {
+    /// Dive duration in minutes, `None` = no alarm.
+    pub minutes: Option<f64>,
+    reading: f64, // timer reading at the last check, in minutes
+}
+
+impl DiveAlarm {
+    pub fn new(minutes: Option<f64>) -> Self {
+        Self { minutes, reading: 0.0 }
+    }
+
+    /// Take a new timer reading as time goes by. Returns true once, when the
+    /// reading first reaches the duration.
+    pub fn check(&mut self, reading: f64) -> bool {
+        let previous = std::mem::replace(&mut self.reading, reading);
+        self.minutes.is_some_and(|minutes| previous < minutes && reading >= minutes)
+    }
+
+    /// Carry on from `reading` without sounding, as after the bezel is turned.
+    pub fn restart(&mut self, reading: f64) {
+        self.reading = reading;
+    }
+}
@@ -0,0 +1,98 @@
+//! `dialup export` and the screenshot key: the face drawn to an image file,
+//! or animated over a stretch of simulated time.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use chrono::{DateTime, Local, TimeDelta, Utc};
+use dialup::animation::Gif;
+use dialup::scene::Scene;
+use dialup::time_source::FixedClock;
+use dialup::zone::Zone;
+use dialup::{png, svg};
+
+use crate::app::App;
+use crate::cli::{Export, ExportTime};
+use crate::render;
+
+/// Width and height of a screenshot, in pixels.
+const SCREENSHOT_WIDTH: u32 = 1024;
+
+/// Most GIF frames a second; viewers slow down anything shorter than 2/100 s.
+const MAX_GIF_FPS: u32 = 50;
+
+/// The instant `--time` names, read in `zone` when it is a wall-clock time.
+pub fn resolve_time(time: Option<ExportTime>, zone: Zone, now: DateTime<Utc>) -> Result<DateTime<Utc>, String> {
+    match time {
+        None => Ok(now),
+        Some(ExportTime::Instant(instant)) => Ok(instant),
+        Some(ExportTime::Wall(date, time)) => {
+            let date = date.unwrap_or_else(|| (now + zone.offset_at(now)).date_naive());
+            let wall = date.and_time(time);
+            zone.instant_at(wall)
+                .ok_or_else(|| format!("{} does not exist in {}: the clocks skip it", wall, zone))
+        }
+    }
+}
+
+/// Draw the app's face at its clock's time and write it where `export` says.
+pub fn write(export: &Export, app: &mut App) -> io::Result<()> {
+    // The stars twinkle from the start of the animation
+    let scene = scene(app, 0.0);
+    if let Some(path) = &export.svg {
+        output(path, svg::render(&scene, export.width).as_bytes())?;
+    }
+    if let Some(path) = &export.png {
+        output(path, &png::render(&scene, export.width)?)?;
+    }
+    if let Some(path) = &export.gif {
+        output(path, &gif(app, export.duration, export.width)?)?;
+    }
+    Ok(())
+}
+
+/// `duration` seconds from the app's clock's time as an animated GIF, at
+/// the app's frame rate. Each frame stops the clock at its own time.
+fn gif(app: &mut App, duration: f64, width: u32) -> io::Result<Vec<u8>> {
+    let fps = app.fps.min(MAX_GIF_FPS);
+    let start = app.clock.now();
+    let frames = ((duration * fps as f64).round() as u32).max(1);
+    let side = u16::try_from(width).map_err(io::Error::other)?;
+    let mut gif = Gif::new(Vec::new(), side, fps)?;
+    for i in 0..frames {
+        let elapsed = i as f64 / fps as f64;
+        app.clock = Box::new(FixedClock(start + TimeDelta::microseconds((elapsed * 1e6) as i64)));
+        gif.frame(&png::rasterise(&scene(app, elapsed), width))?;
+    }
+    app.clock = Box::new(FixedClock(start));
+    gif.into_inner()
+}
+
+/// Save the face as it is drawn now to a timestamped PNG in the current
+/// directory, returning its path.
+pub fn screenshot(app: &App) -> io::Result<PathBuf> {
+    let path = PathBuf::from(Local::now().format("dialup-%Y%m%d-%H%M%S.png").to_string());
+    let scene = scene(app, app.elapsed_secs());
+    fs::write(&path, png::render(&scene, SCREENSHOT_WIDTH)?)?;
+    Ok(path)
+}
+
+/// The app's single face as geometry, with the stars `elapsed` seconds in.
+fn scene(app: &App, elapsed: f64) -> Scene {
+    let theme = app.themes.get(app.theme_index);
+    let mut face = render::face(theme, app, app.zone).elapsed(elapsed);
+    if app.zone != Zone::Local {
+        face = face.label(app.zone.label());
+    }
+    face.scene(&app.face.stars)
+}
+
+/// Write to the file at `path`, or to standard output for `-`.
+fn output(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    if path == Path::new("-") {
+        io::stdout().write_all(bytes)
+    } else {
+        fs::write(path, bytes)
+    }
+}
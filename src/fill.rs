@@ -0,0 +1,172 @@
+//! Filled shapes for ratatui's [`Canvas`](ratatui::widgets::canvas::Canvas),
+//! which only draws outlines itself.
+//!
+//! Each shape is rasterised by sampling every grid point it covers, so it fills
+//! at the marker's own resolution: 2 × 4 dots per cell with Braille, 1 × 2 with
+//! half blocks. Angles are clock angles — 0 at 12 o'clock, increasing clockwise.
+
+use ratatui::style::Color;
+use ratatui::widgets::canvas::{Painter, Shape};
+use std::f64::consts::TAU;
+
+/// Samples per grid point along each axis.
+const OVERSAMPLE: usize = 2;
+
+/// A filled polygon, closed from the last point back to the first. Self-
+/// intersecting outlines are filled by the even-odd rule.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Polygon<'a> {
+    pub points: &'a [(f64, f64)],
+    pub color: Color,
+}
+
+/// A filled circle.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Disc {
+    pub x: f64,
+    pub y: f64,
+    pub radius: f64,
+    pub color: Color,
+}
+
+/// The part of a ring around the origin between two clock angles, from `from`
+/// clockwise to `to`. With `to - from` of a full turn or more it is the whole ring.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AnnulusSector {
+    pub inner: f64,
+    pub outer: f64,
+    pub from: f64,
+    pub to: f64,
+    pub color: Color,
+}
+
+impl Shape for Polygon<'_> {
+    fn draw(&self, painter: &mut Painter<'_, '_>) {
+        let Some(&(x0, y0)) = self.points.first() else { return };
+        let (mut min, mut max) = ((x0, y0), (x0, y0));
+        for &(x, y) in self.points {
+            min = (min.0.min(x), min.1.min(y));
+            max = (max.0.max(x), max.1.max(y));
+        }
+        fill(painter, min, max, self.color, |x, y| {
+            let mut inside = false;
+            let mut j = self.points.len() - 1;
+            for (i, &(xi, yi)) in self.points.iter().enumerate() {
+                let (xj, yj) = self.points[j];
+                if (yi > y) != (yj > y) && x < xi + (y - yi) / (yj - yi) * (xj - xi) {
+                    inside = !inside;
+                }
+                j = i;
+            }
+            inside
+        });
+    }
+}
+
+impl Shape for Disc {
+    fn draw(&self, painter: &mut Painter<'_, '_>) {
+        let r = self.radius;
+        fill(painter, (self.x - r, self.y - r), (self.x + r, self.y + r), self.color, |x, y| {
+            (x - self.x).powi(2) + (y - self.y).powi(2) <= r * r
+        });
+    }
+}
+
+impl Shape for AnnulusSector {
+    fn draw(&self, painter: &mut Painter<'_, '_>) {
+        let r = self.outer;
+        let span = self.to - self.from;
+        let (min, max) = if span >= TAU {
+            ((-r, -r), (r, r))
+        } else {
+            // Bounded by the end points and any quarter turns the sector crosses
+            let mut angles = vec![self.from, self.to];
+            let first = (self.from / (TAU / 4.0)).ceil() as i32;
+            let last = (self.to / (TAU / 4.0)).floor() as i32;
+            angles.extend((first..=last).map(|q| q as f64 * TAU / 4.0));
+            let mut min = (f64::MAX, f64::MAX);
+            let mut max = (f64::MIN, f64::MIN);
+            for a in angles {
+                for radius in [self.inner, r] {
+                    let (x, y) = (radius * a.sin(), radius * a.cos());
+                    min = (min.0.min(x), min.1.min(y));
+                    max = (max.0.max(x), max.1.max(y));
+                }
+            }
+            (min, max)
+        };
+        fill(painter, min, max, self.color, |x, y| {
+            let d2 = x * x + y * y;
+            if d2 < self.inner * self.inner || d2 > r * r {
+                return false;
+            }
+            // Clock angle of the point, measured on from `from`
+            span >= TAU || (x.atan2(y) - self.from).rem_euclid(TAU) <= span
+        });
+    }
+}
+
+/// Steps along each side of a shape's box when looking for a part of it on
+/// the canvas.
+const PROBES: usize = 32;
+
+/// Paint every grid point between the corners `min` and `max` that has a
+/// sample inside the shape. The box is first cut down to the part of it on
+/// the canvas, whose corners then give the grid resolution.
+fn fill(
+    painter: &mut Painter, min: (f64, f64), max: (f64, f64), color: Color,
+    contains: impl Fn(f64, f64) -> bool,
+) {
+    let Some((min, max)) = clamp(painter, min, max) else { return };
+    // The grid's y axis runs downwards
+    let (Some((gx0, gy0)), Some((gx1, gy1))) = (painter.get_point(min.0, max.1), painter.get_point(max.0, min.1)) else {
+        return;
+    };
+    let cols = (gx1 - gx0 + 1) * OVERSAMPLE;
+    let rows = (gy1 - gy0 + 1) * OVERSAMPLE;
+    let (dx, dy) = ((max.0 - min.0) / cols as f64, (max.1 - min.1) / rows as f64);
+    for row in 0..rows {
+        let y = min.1 + (row as f64 + 0.5) * dy;
+        for col in 0..cols {
+            let x = min.0 + (col as f64 + 0.5) * dx;
+            if contains(x, y) {
+                if let Some((px, py)) = painter.get_point(x, y) {
+                    painter.paint(px, py, color);
+                }
+            }
+        }
+    }
+}
+
+/// The part of the box between `min` and `max` that is on the canvas, or
+/// `None` if none of it is. The painter doesn't expose the canvas bounds, so
+/// they are found from a point on the canvas by bisecting towards each side.
+fn clamp(painter: &Painter, min: (f64, f64), max: (f64, f64)) -> Option<((f64, f64), (f64, f64))> {
+    if painter.get_point(min.0, min.1).is_some() && painter.get_point(max.0, max.1).is_some() {
+        return Some((min, max));
+    }
+    let along = |from: f64, to: f64, i: usize| from + (to - from) * i as f64 / PROBES as f64;
+    let (x, y) = (0..=PROBES)
+        .flat_map(|i| (0..=PROBES).map(move |j| (along(min.0, max.0, i), along(min.1, max.1, j))))
+        .find(|&(x, y)| painter.get_point(x, y).is_some())?;
+
+    // The furthest point from `on` towards `edge` that is still on the canvas
+    let limit = |on: f64, edge: f64, at: &dyn Fn(f64) -> (f64, f64)| {
+        let (mut on, mut off) = (on, edge);
+        let (ex, ey) = at(edge);
+        if painter.get_point(ex, ey).is_some() {
+            return edge;
+        }
+        for _ in 0..48 {
+            let mid = (on + off) / 2.0;
+            let (mx, my) = at(mid);
+            if painter.get_point(mx, my).is_some() { on = mid } else { off = mid }
+        }
+        on
+    };
+    let left = limit(x, min.0, &|x| (x, y));
+    let right = limit(x, max.0, &|x| (x, y));
+    let bottom = limit(y, min.1, &|y| (x, y));
+    let top = limit(y, max.1, &|y| (x, y));
+    Some(((left, bottom), (right, top)))
+}
@@ -0,0 +1,222 @@
+//! Stroke font for text drawn on the canvas: each glyph is a list of line
+//! segments on a 6 × 10 grid, origin at the bottom left.
+//!
+//! Covers A–Z (lower case maps onto upper), 0–9, space and the punctuation
+//! `. , : ; - + / & ' " ! ? ( )`. The watch face draws it straight for the
+//! logo and along an arc for city names.
+
+pub type Segs = &'static [(f64, f64, f64, f64)];
+
+/// Width and height of the glyph grid.
+pub const GLYPH_W: f64 = 6.0;
+pub const GLYPH_H: f64 = 10.0;
+/// Space between glyphs, in grid units.
+pub const GLYPH_GAP: f64 = 2.5;
+
+const A: Segs = &[
+    (0.0, 0.0, 0.0, 7.0), (0.0, 7.0, 3.0, 10.0), (3.0, 10.0, 6.0, 7.0),
+    (6.0, 7.0, 6.0, 0.0), (0.0, 5.0, 6.0, 5.0),
+];
+const B: Segs = &[
+    (0.0, 0.0, 0.0, 10.0), (0.0, 10.0, 4.5, 10.0), (4.5, 10.0, 5.5, 9.0),
+    (5.5, 9.0, 5.5, 6.0), (5.5, 6.0, 4.5, 5.0), (0.0, 5.0, 4.5, 5.0),
+    (4.5, 5.0, 6.0, 4.0), (6.0, 4.0, 6.0, 1.0), (6.0, 1.0, 5.0, 0.0),
+    (5.0, 0.0, 0.0, 0.0),
+];
+const C: Segs = &[
+    (6.0, 10.0, 0.0, 10.0), (0.0, 10.0, 0.0, 0.0), (0.0, 0.0, 6.0, 0.0),
+];
+const D: Segs = &[
+    (0.0, 0.0, 0.0, 10.0), (0.0, 10.0, 4.0, 10.0), (4.0, 10.0, 6.0, 8.0),
+    (6.0, 8.0, 6.0, 2.0), (6.0, 2.0, 4.0, 0.0), (4.0, 0.0, 0.0, 0.0),
+];
+const E: Segs = &[
+    (0.0, 0.0, 0.0, 10.0), (0.0, 10.0, 6.0, 10.0),
+    (0.0, 5.0, 4.0, 5.0), (0.0, 0.0, 6.0, 0.0),
+];
+const F: Segs = &[
+    (0.0, 0.0, 0.0, 10.0), (0.0, 10.0, 6.0, 10.0), (0.0, 5.0, 4.0, 5.0),
+];
+const G: Segs = &[
+    (6.0, 10.0, 0.0, 10.0), (0.0, 10.0, 0.0, 0.0), (0.0, 0.0, 6.0, 0.0),
+    (6.0, 0.0, 6.0, 5.0), (6.0, 5.0, 3.0, 5.0),
+];
+const H: Segs = &[
+    (0.0, 0.0, 0.0, 10.0), (6.0, 0.0, 6.0, 10.0), (0.0, 5.0, 6.0, 5.0),
+];
+const I: Segs = &[
+    (1.0, 10.0, 5.0, 10.0), (3.0, 10.0, 3.0, 0.0), (1.0, 0.0, 5.0, 0.0),
+];
+const J: Segs = &[
+    (6.0, 10.0, 6.0, 0.0), (6.0, 0.0, 0.0, 0.0), (0.0, 0.0, 0.0, 3.0),
+];
+const K: Segs = &[
+    (0.0, 0.0, 0.0, 10.0), (6.0, 10.0, 0.0, 5.0), (0.0, 5.0, 6.0, 0.0),
+];
+const L: Segs = &[
+    (0.0, 10.0, 0.0, 0.0), (0.0, 0.0, 6.0, 0.0),
+];
+const M: Segs = &[
+    (0.0, 0.0, 0.0, 10.0), (0.0, 10.0, 3.0, 5.0), (3.0, 5.0, 6.0, 10.0),
+    (6.0, 10.0, 6.0, 0.0),
+];
+const N: Segs = &[
+    (0.0, 0.0, 0.0, 10.0), (0.0, 10.0, 6.0, 0.0), (6.0, 0.0, 6.0, 10.0),
+];
+const O: Segs = &[
+    (0.0, 0.0, 0.0, 10.0), (0.0, 10.0, 6.0, 10.0), (6.0, 10.0, 6.0, 0.0),
+    (6.0, 0.0, 0.0, 0.0),
+];
+const P: Segs = &[
+    (0.0, 0.0, 0.0, 10.0), (0.0, 10.0, 6.0, 10.0), (6.0, 10.0, 6.0, 5.0),
+    (6.0, 5.0, 0.0, 5.0),
+];
+const Q: Segs = &[
+    (0.0, 0.0, 0.0, 10.0), (0.0, 10.0, 6.0, 10.0), (6.0, 10.0, 6.0, 0.0),
+    (6.0, 0.0, 0.0, 0.0), (3.5, 2.5, 6.0, 0.0),
+];
+const R: Segs = &[
+    (0.0, 0.0, 0.0, 10.0), (0.0, 10.0, 6.0, 10.0),
+    (6.0, 10.0, 6.0, 5.0), (6.0, 5.0, 0.0, 5.0),
+    (0.0, 5.0, 6.0, 0.0),
+];
+const S: Segs = &[
+    (6.0, 10.0, 0.0, 10.0), (0.0, 10.0, 0.0, 5.0),
+    (0.0, 5.0, 6.0, 5.0), (6.0, 5.0, 6.0, 0.0), (6.0, 0.0, 0.0, 0.0),
+];
+const T: Segs = &[
+    (0.0, 10.0, 6.0, 10.0), (3.0, 10.0, 3.0, 0.0),
+];
+const U: Segs = &[
+    (0.0, 10.0, 0.0, 0.0), (0.0, 0.0, 6.0, 0.0), (6.0, 0.0, 6.0, 10.0),
+];
+const V: Segs = &[
+    (0.0, 10.0, 3.0, 0.0), (3.0, 0.0, 6.0, 10.0),
+];
+const W: Segs = &[
+    (0.0, 10.0, 1.5, 0.0), (1.5, 0.0, 3.0, 5.0), (3.0, 5.0, 4.5, 0.0),
+    (4.5, 0.0, 6.0, 10.0),
+];
+const X: Segs = &[
+    (0.0, 10.0, 6.0, 0.0), (0.0, 0.0, 6.0, 10.0),
+];
+const Y: Segs = &[
+    (0.0, 10.0, 3.0, 5.0), (6.0, 10.0, 3.0, 5.0), (3.0, 5.0, 3.0, 0.0),
+];
+const Z: Segs = &[
+    (0.0, 10.0, 6.0, 10.0), (6.0, 10.0, 0.0, 0.0), (0.0, 0.0, 6.0, 0.0),
+];
+
+const DIGIT_0: Segs = &[
+    (0.0, 0.0, 0.0, 10.0), (0.0, 10.0, 6.0, 10.0), (6.0, 10.0, 6.0, 0.0),
+    (6.0, 0.0, 0.0, 0.0), (0.0, 0.0, 6.0, 10.0),
+];
+const DIGIT_1: Segs = &[
+    (1.5, 8.5, 3.0, 10.0), (3.0, 10.0, 3.0, 0.0), (1.0, 0.0, 5.0, 0.0),
+];
+const DIGIT_2: Segs = &[
+    (0.0, 10.0, 6.0, 10.0), (6.0, 10.0, 6.0, 5.0), (6.0, 5.0, 0.0, 5.0),
+    (0.0, 5.0, 0.0, 0.0), (0.0, 0.0, 6.0, 0.0),
+];
+const DIGIT_3: Segs = &[
+    (0.0, 10.0, 6.0, 10.0), (6.0, 10.0, 6.0, 0.0), (6.0, 0.0, 0.0, 0.0),
+    (1.0, 5.0, 6.0, 5.0),
+];
+const DIGIT_4: Segs = &[
+    (0.0, 10.0, 0.0, 5.0), (0.0, 5.0, 6.0, 5.0), (6.0, 10.0, 6.0, 0.0),
+];
+const DIGIT_5: Segs = &[
+    (6.0, 10.0, 0.0, 10.0), (0.0, 10.0, 0.0, 6.0), (0.0, 6.0, 4.5, 6.0),
+    (4.5, 6.0, 6.0, 4.5), (6.0, 4.5, 6.0, 1.5), (6.0, 1.5, 4.5, 0.0),
+    (4.5, 0.0, 0.0, 0.0),
+];
+const DIGIT_6: Segs = &[
+    (6.0, 10.0, 0.0, 10.0), (0.0, 10.0, 0.0, 0.0), (0.0, 0.0, 6.0, 0.0),
+    (6.0, 0.0, 6.0, 5.0), (6.0, 5.0, 0.0, 5.0),
+];
+const DIGIT_7: Segs = &[
+    (0.0, 10.0, 6.0, 10.0), (6.0, 10.0, 2.0, 0.0),
+];
+const DIGIT_8: Segs = &[
+    (0.0, 0.0, 0.0, 10.0), (0.0, 10.0, 6.0, 10.0), (6.0, 10.0, 6.0, 0.0),
+    (6.0, 0.0, 0.0, 0.0), (0.0, 5.0, 6.0, 5.0),
+];
+const DIGIT_9: Segs = &[
+    (6.0, 5.0, 0.0, 5.0), (0.0, 5.0, 0.0, 10.0), (0.0, 10.0, 6.0, 10.0),
+    (6.0, 10.0, 6.0, 0.0), (6.0, 0.0, 0.0, 0.0),
+];
+
+const PERIOD: Segs = &[(2.5, 0.0, 3.5, 0.0), (3.5, 0.0, 3.5, 1.0), (3.5, 1.0, 2.5, 1.0), (2.5, 1.0, 2.5, 0.0)];
+const COMMA: Segs = &[(3.5, 1.0, 3.5, 0.0), (3.5, 0.0, 2.0, -2.0)];
+const COLON: Segs = &[(3.0, 1.0, 3.0, 0.0), (3.0, 7.0, 3.0, 6.0)];
+const SEMICOLON: Segs = &[(3.0, 7.0, 3.0, 6.0), (3.5, 1.0, 3.5, 0.0), (3.5, 0.0, 2.0, -2.0)];
+const HYPHEN: Segs = &[(1.0, 5.0, 5.0, 5.0)];
+const PLUS: Segs = &[(0.5, 5.0, 5.5, 5.0), (3.0, 2.5, 3.0, 7.5)];
+const SLASH: Segs = &[(0.5, 0.0, 5.5, 10.0)];
+const AMPERSAND: Segs = &[
+    (6.0, 0.0, 1.0, 7.0), (1.0, 7.0, 1.0, 9.0), (1.0, 9.0, 2.0, 10.0),
+    (2.0, 10.0, 3.0, 10.0), (3.0, 10.0, 4.0, 9.0), (4.0, 9.0, 4.0, 7.5),
+    (4.0, 7.5, 0.0, 3.5), (0.0, 3.5, 0.0, 1.0), (0.0, 1.0, 1.0, 0.0),
+    (1.0, 0.0, 3.0, 0.0), (3.0, 0.0, 6.0, 3.5),
+];
+const APOSTROPHE: Segs = &[(3.0, 10.0, 3.0, 7.5)];
+const QUOTE: Segs = &[(2.0, 10.0, 2.0, 7.5), (4.0, 10.0, 4.0, 7.5)];
+const EXCLAMATION: Segs = &[(3.0, 10.0, 3.0, 3.0), (3.0, 1.0, 3.0, 0.0)];
+const QUESTION: Segs = &[
+    (0.0, 8.0, 0.0, 10.0), (0.0, 10.0, 6.0, 10.0), (6.0, 10.0, 6.0, 5.0),
+    (6.0, 5.0, 3.0, 5.0), (3.0, 5.0, 3.0, 3.0), (3.0, 1.0, 3.0, 0.0),
+];
+const PAREN_LEFT: Segs = &[(4.0, 10.0, 2.0, 7.5), (2.0, 7.5, 2.0, 2.5), (2.0, 2.5, 4.0, 0.0)];
+const PAREN_RIGHT: Segs = &[(2.0, 10.0, 4.0, 7.5), (4.0, 7.5, 4.0, 2.5), (4.0, 2.5, 2.0, 0.0)];
+const SPACE: Segs = &[];
+
+/// Strokes for a character, or `None` if the font has no glyph for it.
+/// Letters are upper case only; lower case maps to the same glyphs.
+pub fn glyph(c: char) -> Option<Segs> {
+    let segs = match c.to_ascii_uppercase() {
+        'A' => A, 'B' => B, 'C' => C, 'D' => D, 'E' => E, 'F' => F, 'G' => G,
+        'H' => H, 'I' => I, 'J' => J, 'K' => K, 'L' => L, 'M' => M, 'N' => N,
+        'O' => O, 'P' => P, 'Q' => Q, 'R' => R, 'S' => S, 'T' => T, 'U' => U,
+        'V' => V, 'W' => W, 'X' => X, 'Y' => Y, 'Z' => Z,
+        '0' => DIGIT_0, '1' => DIGIT_1, '2' => DIGIT_2, '3' => DIGIT_3, '4' => DIGIT_4,
+        '5' => DIGIT_5, '6' => DIGIT_6, '7' => DIGIT_7, '8' => DIGIT_8, '9' => DIGIT_9,
+        '.' => PERIOD, ',' => COMMA, ':' => COLON, ';' => SEMICOLON,
+        '-' => HYPHEN, '+' => PLUS, '/' => SLASH, '&' => AMPERSAND,
+        '\'' => APOSTROPHE, '"' => QUOTE, '!' => EXCLAMATION, '?' => QUESTION,
+        '(' => PAREN_LEFT, ')' => PAREN_RIGHT, ' ' => SPACE,
+        _ => return None,
+    };
+    Some(segs)
+}
+
+/// Glyph box size and spacing of drawn text, in canvas units.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TextSize {
+    pub width: f64,
+    pub height: f64,
+    pub gap: f64,
+}
+
+impl TextSize {
+    /// Text `height` units tall, in the font's own proportions.
+    pub fn of_height(height: f64) -> Self {
+        let scale = height / GLYPH_H;
+        Self { width: GLYPH_W * scale, height, gap: GLYPH_GAP * scale }
+    }
+
+    /// Distance from one glyph's left edge to the next.
+    pub fn advance(&self) -> f64 {
+        self.width + self.gap
+    }
+
+    /// Width of a line of text, counting only drawable characters.
+    pub fn measure(&self, text: &str) -> f64 {
+        let n = text.chars().filter(|&c| glyph(c).is_some()).count() as f64;
+        if n == 0.0 { 0.0 } else { n * self.advance() - self.gap }
+    }
+
+    /// The same proportions, `factor` times the size.
+    pub fn scaled(&self, factor: f64) -> Self {
+        Self { width: self.width * factor, height: self.height * factor, gap: self.gap * factor }
+    }
+}
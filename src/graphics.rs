@@ -0,0 +1,248 @@
+//! Inline images for terminals with a graphics protocol, for a face sharper
+//! than the 2 × 4 dots of a Braille cell.
+//!
+//! Each encoder returns the escape sequence that draws an image at the
+//! cursor; the caller moves the cursor and writes it out. Nothing here talks
+//! to a terminal, so the output can be checked byte for byte.
+
+use std::fmt::{self, Write};
+use std::io;
+use std::str::FromStr;
+
+use tiny_skia::Pixmap;
+
+use crate::png;
+use crate::scene::Scene;
+
+/// Removes every image the Kitty protocol has placed.
+pub const KITTY_CLEAR: &str = "\x1b_Ga=d,q=2\x1b\\";
+
+/// Base64 payload bytes per Kitty escape sequence.
+const KITTY_CHUNK: usize = 4096;
+
+/// A terminal graphics protocol.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Protocol {
+    /// Kitty's graphics protocol, also spoken by Ghostty and Konsole.
+    Kitty,
+    /// DEC Sixel, as in foot, mlterm and xterm built with it.
+    Sixel,
+    /// iTerm2's inline images, also spoken by WezTerm.
+    Iterm2,
+}
+
+/// Whether to draw the face as an inline image.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Mode {
+    /// Always draw on the character canvas.
+    #[default]
+    Off,
+    /// Use the protocol the environment names, or the canvas if none.
+    Auto,
+    /// Use this protocol.
+    Only(Protocol),
+}
+
+impl Mode {
+    /// The protocol to draw with, if any, given a way to read environment
+    /// variables.
+    pub fn protocol(self, var: impl Fn(&str) -> Option<String>) -> Option<Protocol> {
+        match self {
+            Mode::Off => None,
+            Mode::Auto => Protocol::detect(var),
+            Mode::Only(protocol) => Some(protocol),
+        }
+    }
+}
+
+impl Protocol {
+    /// Guess the protocol from the environment the terminal sets. Inside
+    /// tmux or screen images would need passing through, so none is used.
+    pub fn detect(var: impl Fn(&str) -> Option<String>) -> Option<Protocol> {
+        let term = var("TERM").unwrap_or_default();
+        let program = var("TERM_PROGRAM").unwrap_or_default();
+        if var("TMUX").is_some() || term.starts_with("screen") || term.starts_with("tmux") {
+            None
+        } else if var("KITTY_WINDOW_ID").is_some() || term == "xterm-kitty" || term == "xterm-ghostty" {
+            Some(Protocol::Kitty)
+        } else if program == "iTerm.app" || program == "WezTerm" {
+            Some(Protocol::Iterm2)
+        } else if term.starts_with("foot") || term.starts_with("mlterm") || term.contains("sixel") {
+            Some(Protocol::Sixel)
+        } else {
+            None
+        }
+    }
+
+    /// The escape sequence drawing `scene` at the cursor, `side` pixels square.
+    pub fn encode(self, scene: &Scene, side: u32) -> io::Result<String> {
+        Ok(match self {
+            Protocol::Kitty => kitty(&png::render(scene, side)?),
+            Protocol::Iterm2 => iterm2(&png::render(scene, side)?, side),
+            Protocol::Sixel => sixel(&png::rasterise(scene, side)),
+        })
+    }
+}
+
+impl fmt::Display for Mode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mode::Off => write!(f, "off"),
+            Mode::Auto => write!(f, "auto"),
+            Mode::Only(Protocol::Kitty) => write!(f, "kitty"),
+            Mode::Only(Protocol::Sixel) => write!(f, "sixel"),
+            Mode::Only(Protocol::Iterm2) => write!(f, "iterm2"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseModeError(String);
+
+impl fmt::Display for ParseModeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown graphics mode '{}' (expected off, auto, kitty, sixel or iterm2)", self.0)
+    }
+}
+
+impl std::error::Error for ParseModeError {}
+
+impl FromStr for Mode {
+    type Err = ParseModeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "off" => Ok(Mode::Off),
+            "auto" => Ok(Mode::Auto),
+            "kitty" => Ok(Mode::Only(Protocol::Kitty)),
+            "sixel" => Ok(Mode::Only(Protocol::Sixel)),
+            "iterm2" | "iterm" => Ok(Mode::Only(Protocol::Iterm2)),
+            _ => Err(ParseModeError(s.to_string())),
+        }
+    }
+}
+
+/// A PNG for the Kitty protocol, shown at its own size behind the text
+/// (`z=-1`) without moving the cursor. Long payloads are split over several
+/// sequences, as the protocol asks.
+pub fn kitty(png: &[u8]) -> String {
+    let payload = base64(png);
+    let chunks: Vec<&str> = payload.as_bytes()
+        .chunks(KITTY_CHUNK)
+        .map(|chunk| std::str::from_utf8(chunk).expect("base64 is ASCII"))
+        .collect();
+    let mut out = String::new();
+    for (i, chunk) in chunks.iter().enumerate() {
+        let more = u8::from(i + 1 < chunks.len());
+        if i == 0 {
+            let _ = write!(out, "\x1b_Ga=T,f=100,q=2,C=1,z=-1,m={};{}\x1b\\", more, chunk);
+        } else {
+            let _ = write!(out, "\x1b_Gm={};{}\x1b\\", more, chunk);
+        }
+    }
+    out
+}
+
+/// A PNG for iTerm2's inline image protocol, `side` pixels square.
+pub fn iterm2(png: &[u8], side: u32) -> String {
+    format!(
+        "\x1b]1337;File=inline=1;size={};width={side}px;height={side}px;preserveAspectRatio=1;doNotMoveCursor=1:{}\x07",
+        png.len(), base64(png)
+    )
+}
+
+/// A pixmap as Sixel, in the 216 colours of a 6 × 6 × 6 cube. Every pixel
+/// is painted, black included, so the image covers whatever was under it.
+pub fn sixel(pixmap: &Pixmap) -> String {
+    let (width, height) = (pixmap.width() as usize, pixmap.height() as usize);
+    let colors: Vec<usize> = cube_indices(pixmap).into_iter().map(usize::from).collect();
+
+    let mut out = format!("\x1bP0;0;0q\"1;1;{};{}", width, height);
+    let mut used = [false; 216];
+    for &c in &colors {
+        used[c] = true;
+    }
+    for (c, _) in used.iter().enumerate().filter(|(_, &u)| u) {
+        let _ = write!(out, "#{};2;{};{};{}", c, c / 36 * 20, c / 6 % 6 * 20, c % 6 * 20);
+    }
+
+    // Six rows at a time, one pass over the band per colour in it
+    for top in (0..height).step_by(6) {
+        let rows = top..(top + 6).min(height);
+        let mut in_band = [false; 216];
+        for y in rows.clone() {
+            for &c in &colors[y * width..(y + 1) * width] {
+                in_band[c] = true;
+            }
+        }
+        let mut first = true;
+        for (c, _) in in_band.iter().enumerate().filter(|(_, &u)| u) {
+            if !first {
+                out.push('$');
+            }
+            first = false;
+            let _ = write!(out, "#{}", c);
+            let sixels = (0..width).map(|x| {
+                let bits = rows.clone()
+                    .filter(|&y| colors[y * width + x] == c)
+                    .fold(0, |bits, y| bits | 1 << (y - top));
+                (63 + bits) as u8 as char
+            });
+            push_runs(&mut out, sixels);
+        }
+        out.push('-');
+    }
+    out.push_str("\x1b\\");
+    out
+}
+
+/// Each pixel's nearest colour in the 6 × 6 × 6 cube, as `36 r + 6 g + b`
+/// with each channel from 0 to 5.
+pub(crate) fn cube_indices(pixmap: &Pixmap) -> Vec<u8> {
+    let level = |v: u8| ((v as u16 * 5 + 127) / 255) as u8;
+    pixmap.pixels().iter()
+        .map(|p| level(p.red()) * 36 + level(p.green()) * 6 + level(p.blue()))
+        .collect()
+}
+
+/// Append sixel characters, with runs of four or more as `!<count><char>`.
+fn push_runs(out: &mut String, sixels: impl Iterator<Item = char>) {
+    let flush = |out: &mut String, ch: char, n: usize| {
+        if n >= 4 {
+            let _ = write!(out, "!{}{}", n, ch);
+        } else {
+            out.extend(std::iter::repeat_n(ch, n));
+        }
+    };
+    let mut run: Option<(char, usize)> = None;
+    for ch in sixels {
+        run = match run {
+            Some((prev, n)) if prev == ch => Some((prev, n + 1)),
+            Some((prev, n)) => {
+                flush(out, prev, n);
+                Some((ch, 1))
+            }
+            None => Some((ch, 1)),
+        };
+    }
+    if let Some((ch, n)) = run {
+        flush(out, ch, n);
+    }
+}
+
+/// Standard base64 with padding.
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk.iter().enumerate().fold(0u32, |n, (i, &b)| n | (b as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(ALPHABET[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
@@ -0,0 +1,74 @@
+//! Sends the faces `render` queued as inline images, once ratatui has
+//! drawn the frame's text.
+
+use std::io::{self, Write};
+
+use crossterm::cursor::MoveTo;
+use crossterm::terminal;
+use crossterm::QueueableCommand;
+use dialup::graphics::{Protocol, KITTY_CLEAR};
+
+use crate::app::App;
+
+/// Cell size to assume when the terminal does not report its pixel size.
+const FALLBACK_CELL: (u32, u32) = (8, 16);
+
+/// Largest image side, in pixels.
+const MAX_SIDE: u32 = 2048;
+
+/// Show the images queued this frame, unless they are already on screen.
+///
+/// Returns true when an image has to go that only Kitty can delete: the
+/// caller should clear the terminal, draw the frame again and call this once
+/// more.
+pub fn show(app: &mut App, out: &mut impl Write) -> io::Result<bool> {
+    let Some(protocol) = app.graphics else { return Ok(false) };
+    let images = std::mem::take(&mut app.images);
+    if images == app.shown_images {
+        return Ok(false);
+    }
+    if protocol == Protocol::Kitty {
+        out.write_all(KITTY_CLEAR.as_bytes())?;
+    } else if app.shown_images.iter().any(|(area, _)| images.iter().all(|(a, _)| a != area)) {
+        // Sixel and iTerm2 pixels stay until the cells under them are rewritten
+        app.shown_images.clear();
+        return Ok(true);
+    }
+
+    let (cell_w, cell_h) = match terminal::window_size() {
+        Ok(size) if size.width > 0 && size.height > 0 && size.columns > 0 && size.rows > 0 => {
+            (size.width as u32 / size.columns as u32, size.height as u32 / size.rows as u32)
+        }
+        _ => FALLBACK_CELL,
+    };
+    let (_, screen_rows) = terminal::size()?;
+    for (area, scene) in &images {
+        let mut rows = area.height;
+        if protocol == Protocol::Sixel && area.bottom() >= screen_rows {
+            // A sixel image reaching the last row scrolls the screen
+            rows = rows.saturating_sub(1);
+        }
+        let (w, h) = (area.width as u32 * cell_w, rows as u32 * cell_h);
+        let side = w.min(h).min(MAX_SIDE);
+        if side == 0 {
+            continue;
+        }
+        // Centred to the nearest cell
+        let x = area.x + ((w - side) / 2 / cell_w) as u16;
+        let y = area.y + ((h - side) / 2 / cell_h) as u16;
+        out.queue(MoveTo(x, y))?;
+        out.write_all(protocol.encode(scene, side)?.as_bytes())?;
+    }
+    out.flush()?;
+    app.shown_images = images;
+    Ok(false)
+}
+
+/// Remove any images left on screen before quitting.
+pub fn clear(app: &App, out: &mut impl Write) -> io::Result<()> {
+    if app.graphics == Some(Protocol::Kitty) {
+        out.write_all(KITTY_CLEAR.as_bytes())?;
+        out.flush()?;
+    }
+    Ok(())
+}
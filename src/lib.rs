@@ -0,0 +1,36 @@
+//! A Submariner-inspired analogue watch face for [Ratatui](https://github.com/ratatui/ratatui).
+//!
+//! The face is exposed as the [`WatchFace`] widget so it can be embedded in any
+//! ratatui layout. The `dialup` binary is a thin full-screen wrapper around it.
+//!
+//! ```no_run
+//! use dialup::{clock::ClockHands, theme::submariner::SubmarinerTheme, WatchFace};
+//! # fn draw(frame: &mut ratatui::Frame, area: ratatui::layout::Rect) {
+//! let face = WatchFace::new(&SubmarinerTheme)
+//!     .hands(ClockHands::now(false))
+//!     .lume(true);
+//! frame.render_widget(face, area);
+//! # }
+//! ```
+
+pub mod animation;
+pub mod asciicast;
+pub mod capabilities;
+pub mod clock;
+pub mod dive;
+pub mod fill;
+pub mod font;
+pub mod graphics;
+pub mod marker;
+pub mod png;
+pub mod scene;
+pub mod snapshot;
+pub mod stars;
+pub mod svg;
+pub mod theme;
+pub mod time_source;
+pub mod watch_face;
+pub mod world_time;
+pub mod zone;
+
+pub use watch_face::{WatchFace, WatchFaceState};
@@ -1,39 +1,195 @@
 mod app;
-mod clock;
+mod cli;
+mod config;
+mod export;
+mod inline_images;
 mod render;
-mod theme;
-mod watch_face;
 
-use std::io::{self, stdout};
-use std::time::Duration;
+use std::cell::RefCell;
+use std::fs::File;
+use std::io::{self, stdout, BufWriter, Stdout, Write};
+use std::path::Path;
+use std::process::ExitCode;
+use std::rc::Rc;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
 
+use chrono::Utc;
 use crossterm::{
-    event::{self, Event, KeyCode, KeyEventKind, MouseEventKind, EnableMouseCapture, DisableMouseCapture},
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers, MouseEventKind, EnableMouseCapture, DisableMouseCapture},
     terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
     ExecutableCommand,
 };
 use ratatui::prelude::*;
 
 use app::App;
+use cli::Command;
+use dialup::asciicast::{Cast, Recorder};
+use dialup::capabilities::Capabilities;
+use dialup::theme::registry::ThemeRegistry;
+use dialup::time_source::{FixedClock, SystemClock, TimeSource};
 
-fn main() -> io::Result<()> {
+fn main() -> ExitCode {
+    let (options, export) = match cli::parse(std::env::args().skip(1)) {
+        Ok(Command::Run(options)) => (options, None),
+        Ok(Command::Export(mut export)) => (std::mem::take(&mut export.options), Some(export)),
+        Ok(Command::Help(usage)) => {
+            print!("{}", usage);
+            return ExitCode::SUCCESS;
+        }
+        Ok(Command::Version) => {
+            println!("dialup {}", env!("CARGO_PKG_VERSION"));
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("dialup: error: {}\n\nFor more information, try '--help'.", e);
+            return ExitCode::from(2);
+        }
+    };
+
+    // Precedence: command line > last session > config file > built-in defaults
+    let config = match config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("dialup: error: {}", e);
+            return ExitCode::from(2);
+        }
+    };
+    let themes = ThemeRegistry::load(&config::theme_search_path());
+    let mut options = options;
+    // An export draws what it is asked for, not the last session
+    if config.restore_state && export.is_none() {
+        options = options.or(config::load_state(&themes));
+    }
+    let options = options.or(config.options);
+
+    let clock: Box<dyn TimeSource> = match &export {
+        None => Box::new(SystemClock),
+        Some(export) => {
+            let zone = options.timezone.unwrap_or_default();
+            match export::resolve_time(export.time, zone, Utc::now()) {
+                Ok(instant) => Box::new(FixedClock(instant)),
+                Err(e) => {
+                    eprintln!("dialup: error: invalid value for '--time': {}", e);
+                    return ExitCode::from(2);
+                }
+            }
+        }
+    };
+    let mut app = App::with_clock(themes, clock);
+    if let Some(name) = &options.theme {
+        match app.select_theme(name) {
+            Ok(()) => {}
+            Err(e) => {
+                eprintln!("dialup: error: {}", e);
+                return ExitCode::from(2);
+            }
+        }
+    }
+    app.capabilities = Capabilities::detect(|name| std::env::var(name).ok());
+    app.apply_options(&options);
+
+    if let Some(export) = export {
+        warn_theme_errors(&app);
+        if let Err(e) = export::write(&export, &mut app) {
+            eprintln!("dialup: error: {}", e);
+            return ExitCode::FAILURE;
+        }
+        return ExitCode::SUCCESS;
+    }
+
+    // The alternate screen hides anything printed now, so point at the broken
+    // theme files on the status line and print them once it is left
+    match app.themes.errors().len() {
+        0 => {}
+        1 => app.notify("1 theme file failed to load (see Tab)"),
+        n => app.notify(format!("{} theme files failed to load (see Tab)", n)),
+    }
+    let result = run_terminal(&mut app, options.record.as_deref());
+    warn_theme_errors(&app);
+    if let Err(e) = result {
+        eprintln!("dialup: error: {}", e);
+        return ExitCode::FAILURE;
+    }
+    if let Err(e) = config::save_state(&app) {
+        eprintln!("dialup: warning: could not save session state: {}", e);
+    }
+    ExitCode::SUCCESS
+}
+
+/// Print a warning for each theme file that failed to load. Run after the
+/// theme is selected, which reports a broken file it names as an error.
+fn warn_theme_errors(app: &App) {
+    for e in app.themes.errors() {
+        eprintln!("dialup: warning: {}", e);
+    }
+}
+
+type SessionRecorder = Recorder<Stdout, BufWriter<File>>;
+
+/// Where the terminal's output goes: straight to the terminal, or through a
+/// recorder that also writes it to an asciicast file. The recorder is
+/// shared so the event loop can record resizes.
+enum Output {
+    Terminal(Stdout),
+    Recording(Rc<RefCell<SessionRecorder>>),
+}
+
+impl Output {
+    fn new(record: Option<&Path>) -> io::Result<Self> {
+        let Some(path) = record else { return Ok(Output::Terminal(stdout())) };
+        let (width, height) = terminal::size()?;
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |t| t.as_secs() as i64);
+        let term = std::env::var("TERM").ok();
+        let cast = Cast::new(BufWriter::new(File::create(path)?), width, height, timestamp, term.as_deref())?;
+        Ok(Output::Recording(Rc::new(RefCell::new(Recorder::new(stdout(), cast)))))
+    }
+
+    fn recorder(&self) -> Option<Rc<RefCell<SessionRecorder>>> {
+        match self {
+            Output::Terminal(_) => None,
+            Output::Recording(recorder) => Some(Rc::clone(recorder)),
+        }
+    }
+}
+
+impl Write for Output {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Output::Terminal(out) => out.write(buf),
+            Output::Recording(out) => out.borrow_mut().write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Output::Terminal(out) => out.flush(),
+            Output::Recording(out) => out.borrow_mut().flush(),
+        }
+    }
+}
+
+fn run_terminal(app: &mut App, record: Option<&Path>) -> io::Result<()> {
+    // Open the recording before touching the terminal, so a bad path leaves it be
+    let output = Output::new(record)?;
+    let recorder = output.recorder();
     let original_hook = std::panic::take_hook();
     std::panic::set_hook(Box::new(move |info| {
         let _ = restore_terminal();
         original_hook(info);
     }));
 
-    let mut terminal = setup_terminal()?;
-    let result = run(&mut terminal);
+    let mut terminal = setup_terminal(output)?;
+    let result = run(&mut terminal, app, recorder.as_deref());
     restore_terminal()?;
     result
 }
 
-fn setup_terminal() -> io::Result<Terminal<CrosstermBackend<io::Stdout>>> {
+fn setup_terminal(mut output: Output) -> io::Result<Terminal<CrosstermBackend<Output>>> {
     terminal::enable_raw_mode()?;
-    stdout().execute(EnterAlternateScreen)?;
+    // Through the output, so a recording starts on the alternate screen
+    output.execute(EnterAlternateScreen)?;
     stdout().execute(EnableMouseCapture)?;
-    Terminal::new(CrosstermBackend::new(stdout()))
+    Terminal::new(CrosstermBackend::new(output))
 }
 
 fn restore_terminal() -> io::Result<()> {
@@ -43,41 +199,95 @@ fn restore_terminal() -> io::Result<()> {
     Ok(())
 }
 
-fn run(terminal: &mut Terminal<CrosstermBackend<io::Stdout>>) -> io::Result<()> {
-    let mut app = App::new();
-
+fn run(
+    terminal: &mut Terminal<CrosstermBackend<Output>>,
+    app: &mut App,
+    recorder: Option<&RefCell<SessionRecorder>>,
+) -> io::Result<()> {
+    // Draw when the next frame is due, or straight away after input that
+    // changes something; other events (mouse movement) leave the schedule be
+    let mut next_frame = Instant::now();
     while app.running {
-        terminal.draw(|frame| render::render(frame, &app))?;
+        if app.check_dive_alarm() {
+            // Terminal bell; the readout flashes as well
+            terminal.backend_mut().write_all(b"\x07")?;
+            Write::flush(terminal.backend_mut())?;
+        }
+        if Instant::now() >= next_frame {
+            terminal.draw(|frame| render::render(frame, app))?;
+            if inline_images::show(app, terminal.backend_mut())? {
+                terminal.clear()?;
+                terminal.draw(|frame| render::render(frame, app))?;
+                inline_images::show(app, terminal.backend_mut())?;
+            }
+            next_frame = Instant::now() + app.frame_interval();
+        }
 
-        if event::poll(Duration::from_millis(100))? {
-            match event::read()? {
+        if event::poll(next_frame.saturating_duration_since(Instant::now()))? {
+            let redraw = match event::read()? {
                 Event::Key(key) if key.kind == KeyEventKind::Press => {
-                    match key.code {
-                        KeyCode::Char('q') | KeyCode::Esc => app.quit(),
-                        KeyCode::Char('c')
-                            if key.modifiers.contains(event::KeyModifiers::CONTROL) =>
-                        {
-                            app.quit();
-                        }
-                        KeyCode::Char('s') => app.toggle_stars(),
-                        KeyCode::Char('l') => app.toggle_lume(),
-                        KeyCode::Char('m') => app.toggle_smooth(),
-                        KeyCode::Char('[') => app.rotate_bezel(-3), // counter-clockwise
-                        KeyCode::Char(']') => app.rotate_bezel(3),  // clockwise
-                        _ => {}
-                    }
+                    handle_key(app, key);
+                    true
                 }
-                Event::Mouse(mouse) => {
-                    match mouse.kind {
-                        MouseEventKind::ScrollUp => app.rotate_bezel(-1),   // counter-clockwise
-                        MouseEventKind::ScrollDown => app.rotate_bezel(1),  // clockwise
-                        _ => {}
+                Event::Mouse(mouse) => match mouse.kind {
+                    MouseEventKind::ScrollUp => { app.rotate_bezel(-1); true }   // counter-clockwise
+                    MouseEventKind::ScrollDown => { app.rotate_bezel(1); true }  // clockwise
+                    _ => false,
+                },
+                Event::Resize(width, height) => {
+                    if let Some(recorder) = recorder {
+                        recorder.borrow_mut().resize(width, height)?;
                     }
+                    true
                 }
-                _ => {}
+                _ => false,
+            };
+            if redraw {
+                next_frame = Instant::now();
             }
         }
     }
 
-    Ok(())
+    inline_images::clear(app, terminal.backend_mut())
+}
+
+fn handle_key(app: &mut App, key: KeyEvent) {
+    if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) {
+        app.quit();
+        return;
+    }
+
+    // The theme picker captures navigation keys while it is open
+    if app.picker.is_some() {
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => app.move_picker(-1),
+            KeyCode::Down | KeyCode::Char('j') => app.move_picker(1),
+            KeyCode::Enter => app.confirm_picker(),
+            KeyCode::Esc | KeyCode::Tab | KeyCode::Char('q') => app.close_picker(),
+            _ => {}
+        }
+        return;
+    }
+
+    match key.code {
+        KeyCode::Char('q') | KeyCode::Esc => app.quit(),
+        KeyCode::Char('s') => app.toggle_stars(),
+        KeyCode::Char('l') => app.toggle_lume(),
+        KeyCode::Char('m') => app.cycle_movement(),
+        KeyCode::Char('c') => app.cycle_marker(),
+        KeyCode::Char('t') => app.cycle_theme(1),
+        KeyCode::Char('T') => app.cycle_theme(-1),
+        KeyCode::Tab => app.open_picker(),
+        KeyCode::Char('w') => app.toggle_world(),
+        KeyCode::Char('z') => app.set_bezel_to_now(),
+        KeyCode::Char(' ') => app.toggle_chronograph(),
+        KeyCode::Char('r') => app.reset_chronograph(),
+        KeyCode::Char('p') => match export::screenshot(app) {
+            Ok(path) => app.notify(format!("Saved {}", path.display())),
+            Err(e) => app.notify(format!("Could not save screenshot: {}", e)),
+        },
+        KeyCode::Char('[') => app.rotate_bezel(-app.bezel_step()), // counter-clockwise
+        KeyCode::Char(']') => app.rotate_bezel(app.bezel_step()),  // clockwise
+        _ => {}
+    }
 }
@@ -0,0 +1,127 @@
+//! The symbols the face is drawn with on the character canvas.
+//!
+//! ratatui's canvas markers, plus quadrant blocks, which ratatui does not
+//! draw: the face is drawn in Braille and each cell's 2 × 4 dots are folded
+//! into 2 × 2 quadrants.
+
+use std::fmt;
+use std::str::FromStr;
+
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::symbols;
+
+/// Quadrant blocks, indexed by upper left = 1, upper right = 2, lower left
+/// = 4 and lower right = 8.
+const QUADRANTS: [char; 16] = [
+    ' ', '▘', '▝', '▀', '▖', '▌', '▞', '▛', '▗', '▚', '▐', '▜', '▄', '▙', '▟', '█',
+];
+
+/// How the face is drawn on the canvas, finest first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Marker {
+    /// 2 × 4 dots a cell.
+    Braille,
+    /// 2 × 2 quadrant blocks a cell.
+    Quadrant,
+    /// An upper and a lower half a cell, each in its own colour.
+    HalfBlock,
+    /// A full block a cell.
+    Block,
+    /// A dot a cell.
+    Dot,
+    /// A lower half block a cell.
+    Bar,
+}
+
+impl Marker {
+    /// The markers the override key steps through, after picking automatically.
+    pub const CYCLE: [Marker; 5] = [Marker::Braille, Marker::HalfBlock, Marker::Block, Marker::Dot, Marker::Quadrant];
+
+    /// The marker after `marker` in [`CYCLE`](Self::CYCLE), where `None`
+    /// means picking automatically and comes before and after the rest.
+    pub fn next(marker: Option<Marker>) -> Option<Marker> {
+        match marker.and_then(|m| Self::CYCLE.iter().position(|&c| c == m)) {
+            None if marker.is_none() => Some(Self::CYCLE[0]),
+            Some(i) if i + 1 < Self::CYCLE.len() => Some(Self::CYCLE[i + 1]),
+            _ => None,
+        }
+    }
+
+    /// The ratatui marker the canvas draws with.
+    pub fn canvas(self) -> symbols::Marker {
+        match self {
+            Marker::Braille | Marker::Quadrant => symbols::Marker::Braille,
+            Marker::HalfBlock => symbols::Marker::HalfBlock,
+            Marker::Block => symbols::Marker::Block,
+            Marker::Dot => symbols::Marker::Dot,
+            Marker::Bar => symbols::Marker::Bar,
+        }
+    }
+}
+
+impl From<symbols::Marker> for Marker {
+    fn from(marker: symbols::Marker) -> Self {
+        match marker {
+            symbols::Marker::Braille => Marker::Braille,
+            symbols::Marker::HalfBlock => Marker::HalfBlock,
+            symbols::Marker::Block => Marker::Block,
+            symbols::Marker::Dot => Marker::Dot,
+            symbols::Marker::Bar => Marker::Bar,
+        }
+    }
+}
+
+impl fmt::Display for Marker {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Marker::Braille => write!(f, "braille"),
+            Marker::Quadrant => write!(f, "quadrant"),
+            Marker::HalfBlock => write!(f, "half-block"),
+            Marker::Block => write!(f, "block"),
+            Marker::Dot => write!(f, "dot"),
+            Marker::Bar => write!(f, "bar"),
+        }
+    }
+}
+
+impl FromStr for Marker {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "braille" => Ok(Marker::Braille),
+            "quadrant" | "quadrants" => Ok(Marker::Quadrant),
+            "half-block" | "halfblock" => Ok(Marker::HalfBlock),
+            "block" => Ok(Marker::Block),
+            "dot" => Ok(Marker::Dot),
+            "bar" => Ok(Marker::Bar),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Turn the Braille cells in `area` into quadrant blocks, each quadrant lit
+/// when any of its four dots is. Other cells, such as printed text, are left.
+pub fn fold_quadrants(buf: &mut Buffer, area: Rect) {
+    let area = area.intersection(buf.area);
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let cell = &mut buf[(x, y)];
+            let Some(ch) = cell.symbol().chars().next() else { continue };
+            let bits = (ch as u32).wrapping_sub(0x2800);
+            if bits > 0xff {
+                continue;
+            }
+            let mut quadrants = 0;
+            for (row, dots) in symbols::braille::DOTS.iter().enumerate() {
+                for (col, &dot) in dots.iter().enumerate() {
+                    if bits & dot as u32 != 0 {
+                        quadrants |= 1 << (col + row / 2 * 2);
+                    }
+                }
+            }
+            cell.set_char(QUADRANTS[quadrants]);
+        }
+    }
+}
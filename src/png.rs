@@ -0,0 +1,136 @@
+//! Draws a [`Scene`] as an anti-aliased PNG image with tiny-skia.
+//!
+//! The scene's ±100 space fills the image, with y flipped to run downwards.
+//! Strokes are as wide as in the [`svg`](crate::svg) backend; labels, which
+//! have no font to fall back on here, are drawn in the dial's stroke font.
+
+use std::f64::consts::TAU;
+use std::io;
+
+use ratatui::style::Color;
+use tiny_skia::{FillRule, LineCap, LineJoin, Paint, PathBuilder, Pixmap, Stroke, Transform};
+
+use crate::font::{self, TextSize};
+use crate::scene::{self, Item, Scene};
+
+/// Stroke width in scene units.
+const STROKE: f32 = 0.8;
+/// Label cap height in scene units.
+const LABEL_HEIGHT: f64 = 4.0;
+
+/// The scene as a PNG file `width` pixels square, on a black background.
+pub fn render(scene: &Scene, width: u32) -> io::Result<Vec<u8>> {
+    rasterise(scene, width).encode_png().map_err(io::Error::other)
+}
+
+/// The scene drawn into a pixmap `width` pixels square, on a black background.
+///
+/// # Panics
+///
+/// If `width` is zero.
+pub fn rasterise(scene: &Scene, width: u32) -> Pixmap {
+    let mut pixmap = Pixmap::new(width, width).expect("image width must not be zero");
+    pixmap.fill(tiny_skia::Color::BLACK);
+
+    let scale = width as f32 / 200.0;
+    let half = width as f32 / 2.0;
+    let transform = Transform::from_row(scale, 0.0, 0.0, -scale, half, half);
+    let mut stroke = Stroke { width: STROKE, line_cap: LineCap::Round, line_join: LineJoin::Round, ..Stroke::default() };
+
+    for item in &scene.items {
+        match item {
+            Item::Line { x1, y1, x2, y2, color } => {
+                let mut path = PathBuilder::new();
+                path.move_to(*x1 as f32, *y1 as f32);
+                path.line_to(*x2 as f32, *y2 as f32);
+                if let Some(path) = path.finish() {
+                    pixmap.stroke_path(&path, &paint(*color), &stroke, transform, None);
+                }
+            }
+            Item::Circle { x, y, radius, color } => {
+                if let Some(path) = PathBuilder::from_circle(*x as f32, *y as f32, *radius as f32) {
+                    pixmap.stroke_path(&path, &paint(*color), &stroke, transform, None);
+                }
+            }
+            Item::Polygon { points, color } => {
+                let mut path = PathBuilder::new();
+                for (i, &(x, y)) in points.iter().enumerate() {
+                    if i == 0 {
+                        path.move_to(x as f32, y as f32);
+                    } else {
+                        path.line_to(x as f32, y as f32);
+                    }
+                }
+                path.close();
+                if let Some(path) = path.finish() {
+                    pixmap.fill_path(&path, &paint(*color), FillRule::EvenOdd, transform, None);
+                }
+            }
+            Item::Disc { x, y, radius, color } => {
+                if let Some(path) = PathBuilder::from_circle(*x as f32, *y as f32, *radius as f32) {
+                    pixmap.fill_path(&path, &paint(*color), FillRule::Winding, transform, None);
+                }
+            }
+            Item::AnnulusSector { inner, outer, from, to, color } => {
+                let span = to - from;
+                if span >= TAU {
+                    // A whole ring is one wide stroke
+                    let ring = Stroke { width: (outer - inner) as f32, ..Stroke::default() };
+                    if let Some(path) = PathBuilder::from_circle(0.0, 0.0, ((inner + outer) / 2.0) as f32) {
+                        pixmap.stroke_path(&path, &paint(*color), &ring, transform, None);
+                    }
+                    continue;
+                }
+                // Round the outer edge clockwise and back along the inner,
+                // a step for every degree
+                let steps = ((span / TAU * 360.0).ceil() as usize).max(1);
+                let at = |r: f64, i: usize| {
+                    let a = from + span * i as f64 / steps as f64;
+                    ((r * a.sin()) as f32, (r * a.cos()) as f32)
+                };
+                let mut path = PathBuilder::new();
+                let (x, y) = at(*outer, 0);
+                path.move_to(x, y);
+                for i in 1..=steps {
+                    let (x, y) = at(*outer, i);
+                    path.line_to(x, y);
+                }
+                for i in (0..=steps).rev() {
+                    let (x, y) = at(*inner, i);
+                    path.line_to(x, y);
+                }
+                path.close();
+                if let Some(path) = path.finish() {
+                    pixmap.fill_path(&path, &paint(*color), FillRule::Winding, transform, None);
+                }
+            }
+            Item::Label { x, y, text, color } => {
+                let size = TextSize::of_height(LABEL_HEIGHT);
+                let (sx, sy) = (size.width / font::GLYPH_W, size.height / font::GLYPH_H);
+                let (mut ox, oy) = (x - size.measure(text) / 2.0, y - LABEL_HEIGHT / 2.0);
+                let mut path = PathBuilder::new();
+                for segs in text.chars().filter_map(font::glyph) {
+                    for &(x1, y1, x2, y2) in segs {
+                        path.move_to((ox + x1 * sx) as f32, (oy + y1 * sy) as f32);
+                        path.line_to((ox + x2 * sx) as f32, (oy + y2 * sy) as f32);
+                    }
+                    ox += size.advance();
+                }
+                stroke.width = STROKE * 0.75;
+                if let Some(path) = path.finish() {
+                    pixmap.stroke_path(&path, &paint(*color), &stroke, transform, None);
+                }
+                stroke.width = STROKE;
+            }
+        }
+    }
+    pixmap
+}
+
+fn paint(color: Color) -> Paint<'static> {
+    let (r, g, b) = scene::rgb(color);
+    let mut paint = Paint::default();
+    paint.set_color_rgba8(r, g, b, 255);
+    paint.anti_alias = true;
+    paint
+}
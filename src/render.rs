@@ -1,29 +1,177 @@
 use ratatui::Frame;
-use ratatui::layout::Rect;
+use ratatui::layout::{Alignment, Constraint, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Clear, List, ListItem, ListState, Paragraph};
+use dialup::scene::Scene;
+use dialup::theme::WatchTheme;
+use dialup::watch_face::{self, WatchFace, WatchFaceState};
+use dialup::world_time::WorldTime;
+use dialup::zone::Zone;
 use crate::app::App;
-use crate::watch_face;
 
-pub fn render(frame: &mut Frame, app: &App) {
+pub fn render(frame: &mut Frame, app: &mut App) {
+    let mut area = match app.size {
+        Some((w, h)) => centered(frame.area(), w, h),
+        None => frame.area(),
+    };
+    let notice = app.notice().map(str::to_owned);
+    if (notice.is_some() || app.shows_dive_timer()) && area.height > 1 {
+        let [face_area, status] = Layout::vertical([Constraint::Min(1), Constraint::Length(1)]).areas(area);
+        match notice {
+            Some(text) => render_notice(frame, app, text, status),
+            None => render_dive_timer(frame, app, status),
+        }
+        area = face_area;
+    }
+    if app.world {
+        render_world(frame, app, area);
+    } else {
+        // Name the zone on the dial unless it is the system's own
+        let label = (app.zone != Zone::Local).then(|| app.zone.label());
+        let mut face = face(app.themes.get(app.theme_index), app, app.zone);
+        if let Some(label) = label {
+            face = face.label(label);
+        }
+        let images = shows_images(app).then_some(&mut app.images);
+        draw_face(frame, face, watch_face::square_area(area), &mut app.face, images);
+    }
+
+    if let Some(selected) = app.picker {
+        render_theme_picker(frame, app, selected);
+    }
     let area = frame.area();
-    let square = compute_square_area(area);
-    watch_face::draw(frame, square, app);
+    app.color_depth().reduce_buffer(frame.buffer_mut(), area);
 }
 
-/// Compute the largest visually-square Rect centered in the available area.
-/// Terminal cells are ~2:1 (height:width in pixels), so we need
-/// rect_width = rect_height * 2 in cell units for a visual square.
-fn compute_square_area(area: Rect) -> Rect {
-    let max_w = area.width;
-    let max_h = area.height;
+/// A face for the app's current settings, showing the time in `zone`.
+pub fn face<'a>(theme: &'a dyn WatchTheme, app: &App, zone: Zone) -> WatchFace<'a> {
+    let mut face = WatchFace::new(theme)
+        .hands(app.hands_in(zone))
+        .bezel_offset(app.bezel_angle())
+        .lume(app.lume_mode)
+        .stars(app.stars_enabled)
+        .elapsed(app.elapsed_secs())
+        .chronograph(app.chronograph());
+    if let Some(marker) = app.marker() {
+        face = face.marker(marker);
+    }
+    let cities = theme.world_cities();
+    if !cities.is_empty() {
+        face = face.world_time(WorldTime::at(app.clock.now(), zone, cities));
+    }
+    face
+}
 
-    let (w, h) = if max_w >= max_h * 2 {
-        (max_h * 2, max_h)
-    } else {
-        (max_w, max_w / 2)
+/// Whether faces go out as inline images this frame. The theme picker is
+/// drawn over a canvas, since text cannot cover every protocol's images.
+fn shows_images(app: &App) -> bool {
+    app.graphics.is_some() && app.picker.is_none()
+}
+
+/// Draw a face on the canvas, or queue it to be sent as an image once the
+/// frame's text is out.
+fn draw_face(
+    frame: &mut Frame, face: WatchFace, area: Rect, state: &mut WatchFaceState,
+    images: Option<&mut Vec<(Rect, Scene)>>,
+) {
+    match images {
+        Some(images) => images.push((area, face.scene(&state.stars))),
+        None => frame.render_stateful_widget(face, area, state),
+    }
+}
+
+/// Tile one labelled face per world clock zone, in as many columns as
+/// gives the largest faces.
+fn render_world(frame: &mut Frame, app: &mut App, area: Rect) {
+    let zones = app.world_zones();
+    let (cols, rows) = grid_shape(area, zones.len());
+    let row_areas = Layout::vertical(vec![Constraint::Ratio(1, rows as u32); rows]).split(area);
+    for (row, zones) in zones.chunks(cols).enumerate() {
+        let tiles = Layout::horizontal(vec![Constraint::Ratio(1, cols as u32); cols]).split(row_areas[row]);
+        for (tile, zone) in tiles.iter().zip(zones) {
+            let face = face(app.themes.get(app.theme_index), app, *zone).label(zone.label());
+            let images = shows_images(app).then_some(&mut app.images);
+            draw_face(frame, face, watch_face::square_area(*tile), &mut app.face, images);
+        }
+    }
+}
+
+/// Columns and rows for `n` faces in `area` that make each face largest.
+fn grid_shape(area: Rect, n: usize) -> (usize, usize) {
+    let n = n.max(1);
+    (1..=n)
+        .map(|cols| (cols, n.div_ceil(cols)))
+        .max_by_key(|&(cols, rows)| {
+            // A square face is twice as wide as it is tall, in cells
+            let w = area.width / cols as u16;
+            let h = area.height / rows as u16;
+            w.min(h * 2)
+        })
+        .unwrap_or((1, 1))
+}
+
+/// One-line readout of the bezel timer, flashing once the dive duration is up.
+fn render_dive_timer(frame: &mut Frame, app: &App, area: Rect) {
+    let elapsed = app.dive_elapsed().floor() as u32;
+    let text = match app.dive.minutes {
+        Some(minutes) => format!("DIVE {} / {} min", elapsed, minutes),
+        None => format!("DIVE {} min", elapsed),
     };
+    let mut style = Style::default().fg(app.theme().marker_color());
+    if app.alarm_active() {
+        style = style.fg(Color::Red).add_modifier(Modifier::BOLD);
+        // Blink twice a second
+        if app.elapsed_secs().fract() < 0.5 {
+            style = style.add_modifier(Modifier::REVERSED);
+        }
+    }
+    frame.render_widget(Paragraph::new(text).style(style).alignment(Alignment::Center), area);
+}
+
+/// One-line message, such as where a screenshot was saved.
+fn render_notice(frame: &mut Frame, app: &App, text: String, area: Rect) {
+    let style = Style::default().fg(app.theme().logo_color());
+    frame.render_widget(Paragraph::new(text).style(style).alignment(Alignment::Center), area);
+}
+
+/// Overlay listing every registered theme, with the current one marked.
+fn render_theme_picker(frame: &mut Frame, app: &App, selected: usize) {
+    let mut items: Vec<ListItem> = app.themes.iter().enumerate()
+        .map(|(i, theme)| {
+            let mark = if i == app.theme_index { "● " } else { "  " };
+            ListItem::new(Line::from(format!("{}{}", mark, theme.name())))
+        })
+        .collect();
+    // Files that failed to load are listed after the themes, and cannot be chosen
+    let failed: Vec<String> = app.themes.errors().iter()
+        .filter_map(|e| e.path.as_deref()?.file_name())
+        .map(|name| format!("✗ {} (failed)", name.to_string_lossy()))
+        .collect();
+    items.extend(failed.iter().map(|text| {
+        ListItem::new(Line::from(text.as_str())).style(Style::default().fg(Color::DarkGray))
+    }));
+
+    let width = app.themes.iter()
+        .map(|t| t.name().chars().count() as u16 + 2)
+        .chain(failed.iter().map(|text| text.chars().count() as u16))
+        .max().unwrap_or(0)
+        .max(12) + 4;
+    let height = (app.themes.len() + failed.len()) as u16 + 2;
+    let popup = centered(frame.area(), width, height);
 
-    let x = area.x + (max_w.saturating_sub(w)) / 2;
-    let y = area.y + (max_h.saturating_sub(h)) / 2;
+    let list = List::new(items)
+        .block(Block::bordered().title(" Themes "))
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+    let mut state = ListState::default().with_selected(Some(selected));
+
+    frame.render_widget(Clear, popup);
+    frame.render_stateful_widget(list, popup, &mut state);
+}
 
-    Rect::new(x, y, w, h)
+/// A `w` × `h` Rect centered in `area`, clamped to fit.
+fn centered(area: Rect, w: u16, h: u16) -> Rect {
+    let w = w.min(area.width);
+    let h = h.min(area.height);
+    Rect::new(area.x + (area.width - w) / 2, area.y + (area.height - h) / 2, w, h)
 }
@@ -0,0 +1,164 @@
+//! The watch face as geometry: lines, circles, fills and labels in the
+//! canvas's ±100 coordinate space, independent of any terminal grid.
+//!
+//! [`WatchFace::scene`](crate::WatchFace::scene) records a face into a
+//! [`Scene`], which can then be painted onto a ratatui canvas with
+//! [`Scene::paint`] or handed to another backend such as [`svg`](crate::svg).
+//! Angles are clock angles — 0 at 12 o'clock, increasing clockwise — and y
+//! runs upwards.
+
+use ratatui::style::Color;
+use ratatui::text::Line as TextLine;
+use ratatui::widgets::canvas::{Circle as CanvasCircle, Context, Line as CanvasLine};
+
+use crate::fill;
+
+/// One drawing operation.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Item {
+    /// A straight stroke between two points.
+    Line { x1: f64, y1: f64, x2: f64, y2: f64, color: Color },
+    /// The outline of a circle.
+    Circle { x: f64, y: f64, radius: f64, color: Color },
+    /// A filled polygon, see [`fill::Polygon`].
+    Polygon { points: Vec<(f64, f64)>, color: Color },
+    /// A filled circle.
+    Disc { x: f64, y: f64, radius: f64, color: Color },
+    /// A filled part of a ring around the origin, see [`fill::AnnulusSector`].
+    AnnulusSector { inner: f64, outer: f64, from: f64, to: f64, color: Color },
+    /// Text in the terminal's own font, centred on `x` in the row at `y`.
+    Label { x: f64, y: f64, text: String, color: Color },
+}
+
+/// An ordered list of drawing operations; later items are drawn over
+/// earlier ones.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Scene {
+    pub items: Vec<Item>,
+}
+
+impl Scene {
+    /// Record a shape, using the same shape types as a canvas [`Context`].
+    pub fn draw<'s, S>(&mut self, shape: &'s S)
+    where
+        Item: From<&'s S>,
+    {
+        self.items.push(Item::from(shape));
+    }
+
+    /// Record a label centred on `x`.
+    pub fn label(&mut self, x: f64, y: f64, text: impl Into<String>, color: Color) {
+        self.items.push(Item::Label { x, y, text: text.into(), color });
+    }
+
+    /// Append every item of another scene.
+    pub fn extend(&mut self, other: Scene) {
+        self.items.extend(other.items);
+    }
+
+    /// Paint the scene onto a canvas. Labels are printed in terminal cells,
+    /// `cell_width` canvas units wide each.
+    pub fn paint(&self, ctx: &mut Context, cell_width: f64) {
+        for item in &self.items {
+            match *item {
+                Item::Line { x1, y1, x2, y2, color } => {
+                    ctx.draw(&CanvasLine { x1, y1, x2, y2, color });
+                }
+                Item::Circle { x, y, radius, color } => {
+                    ctx.draw(&CanvasCircle { x, y, radius, color });
+                }
+                Item::Polygon { ref points, color } => {
+                    ctx.draw(&fill::Polygon { points, color });
+                }
+                Item::Disc { x, y, radius, color } => {
+                    ctx.draw(&fill::Disc { x, y, radius, color });
+                }
+                Item::AnnulusSector { inner, outer, from, to, color } => {
+                    ctx.draw(&fill::AnnulusSector { inner, outer, from, to, color });
+                }
+                Item::Label { x, y, ref text, color } => {
+                    let width = text.chars().count() as f64 * cell_width;
+                    ctx.print(x - width / 2.0, y, TextLine::styled(text.clone(), color));
+                }
+            }
+        }
+    }
+}
+
+impl From<&CanvasLine> for Item {
+    fn from(l: &CanvasLine) -> Self {
+        Item::Line { x1: l.x1, y1: l.y1, x2: l.x2, y2: l.y2, color: l.color }
+    }
+}
+
+impl From<&CanvasCircle> for Item {
+    fn from(c: &CanvasCircle) -> Self {
+        Item::Circle { x: c.x, y: c.y, radius: c.radius, color: c.color }
+    }
+}
+
+impl From<&fill::Polygon<'_>> for Item {
+    fn from(p: &fill::Polygon) -> Self {
+        Item::Polygon { points: p.points.to_vec(), color: p.color }
+    }
+}
+
+impl From<&fill::Disc> for Item {
+    fn from(d: &fill::Disc) -> Self {
+        Item::Disc { x: d.x, y: d.y, radius: d.radius, color: d.color }
+    }
+}
+
+impl From<&fill::AnnulusSector> for Item {
+    fn from(s: &fill::AnnulusSector) -> Self {
+        Item::AnnulusSector { inner: s.inner, outer: s.outer, from: s.from, to: s.to, color: s.color }
+    }
+}
+
+/// The colour a typical dark terminal theme shows for `color`, as RGB.
+/// [`Color::Reset`] is taken as the default foreground.
+pub fn rgb(color: Color) -> (u8, u8, u8) {
+    match color {
+        Color::Reset => (0xd0, 0xd0, 0xd0),
+        Color::Black => (0x00, 0x00, 0x00),
+        Color::Red => (0xcd, 0x00, 0x00),
+        Color::Green => (0x00, 0xcd, 0x00),
+        Color::Yellow => (0xcd, 0xcd, 0x00),
+        Color::Blue => (0x00, 0x00, 0xee),
+        Color::Magenta => (0xcd, 0x00, 0xcd),
+        Color::Cyan => (0x00, 0xcd, 0xcd),
+        Color::Gray => (0xe5, 0xe5, 0xe5),
+        Color::DarkGray => (0x7f, 0x7f, 0x7f),
+        Color::LightRed => (0xff, 0x00, 0x00),
+        Color::LightGreen => (0x00, 0xff, 0x00),
+        Color::LightYellow => (0xff, 0xff, 0x00),
+        Color::LightBlue => (0x5c, 0x5c, 0xff),
+        Color::LightMagenta => (0xff, 0x00, 0xff),
+        Color::LightCyan => (0x00, 0xff, 0xff),
+        Color::White => (0xff, 0xff, 0xff),
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(i) => indexed(i),
+    }
+}
+
+/// The xterm 256-colour palette.
+fn indexed(i: u8) -> (u8, u8, u8) {
+    const ANSI: [Color; 16] = [
+        Color::Black, Color::Red, Color::Green, Color::Yellow,
+        Color::Blue, Color::Magenta, Color::Cyan, Color::Gray,
+        Color::DarkGray, Color::LightRed, Color::LightGreen, Color::LightYellow,
+        Color::LightBlue, Color::LightMagenta, Color::LightCyan, Color::White,
+    ];
+    match i {
+        0..=15 => rgb(ANSI[i as usize]),
+        16..=231 => {
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            let i = i - 16;
+            (level(i / 36), level(i / 6 % 6), level(i % 6))
+        }
+        _ => {
+            let v = 8 + (i - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
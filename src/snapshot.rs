@@ -0,0 +1,110 @@
+//! Headless rendering of the watch face, for tests and tooling.
+//!
+//! The face is drawn through ratatui's [`TestBackend`] exactly as the
+//! terminal app draws it, and the resulting buffer can be dumped as plain
+//! text or as ANSI-coloured text.
+
+use std::fmt::Write;
+
+use ratatui::backend::TestBackend;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier};
+use ratatui::Terminal;
+
+use crate::watch_face::{square_area, WatchFace, WatchFaceState};
+
+/// Render `face` into a `width` × `height` terminal, centred and squared
+/// the same way the `dialup` binary lays it out.
+pub fn render(face: WatchFace, width: u16, height: u16) -> Buffer {
+    render_stateful(face, width, height, &mut WatchFaceState::default())
+}
+
+/// Like [`render`], but with an explicit widget state (e.g. a custom star seed).
+pub fn render_stateful(
+    face: WatchFace, width: u16, height: u16, state: &mut WatchFaceState,
+) -> Buffer {
+    let mut terminal = Terminal::new(TestBackend::new(width, height))
+        .expect("test backend never fails");
+    terminal
+        .draw(|frame| {
+            let square = square_area(frame.area());
+            frame.render_stateful_widget(face, square, state);
+        })
+        .expect("test backend never fails");
+    terminal.backend().buffer().clone()
+}
+
+/// The buffer's symbols, one line per row, without any styling.
+pub fn to_text(buf: &Buffer) -> String {
+    let area = buf.area;
+    let mut out = String::new();
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            out.push_str(buf[(x, y)].symbol());
+        }
+        out.push('\n');
+    }
+    out
+}
+
+/// The buffer's symbols with SGR escape sequences for colours and modifiers.
+/// Styling is reset at the end of every line.
+pub fn to_ansi(buf: &Buffer) -> String {
+    let area = buf.area;
+    let mut out = String::new();
+    for y in area.top()..area.bottom() {
+        let mut style = None;
+        for x in area.left()..area.right() {
+            let cell = &buf[(x, y)];
+            let current = (cell.fg, cell.bg, cell.modifier);
+            if style != Some(current) {
+                out.push_str("\x1b[0");
+                push_sgr_color(&mut out, cell.fg, false);
+                push_sgr_color(&mut out, cell.bg, true);
+                if cell.modifier.contains(Modifier::BOLD) {
+                    out.push_str(";1");
+                }
+                if cell.modifier.contains(Modifier::REVERSED) {
+                    out.push_str(";7");
+                }
+                out.push('m');
+                style = Some(current);
+            }
+            out.push_str(cell.symbol());
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+fn push_sgr_color(out: &mut String, color: Color, background: bool) {
+    let base = if background { 40 } else { 30 };
+    let code = match color {
+        Color::Reset => return,
+        Color::Black => base,
+        Color::Red => base + 1,
+        Color::Green => base + 2,
+        Color::Yellow => base + 3,
+        Color::Blue => base + 4,
+        Color::Magenta => base + 5,
+        Color::Cyan => base + 6,
+        Color::Gray => base + 7,
+        Color::DarkGray => base + 60,
+        Color::LightRed => base + 61,
+        Color::LightGreen => base + 62,
+        Color::LightYellow => base + 63,
+        Color::LightBlue => base + 64,
+        Color::LightMagenta => base + 65,
+        Color::LightCyan => base + 66,
+        Color::White => base + 67,
+        Color::Indexed(i) => {
+            let _ = write!(out, ";{};5;{}", base + 8, i);
+            return;
+        }
+        Color::Rgb(r, g, b) => {
+            let _ = write!(out, ";{};2;{};{};{}", base + 8, r, g, b);
+            return;
+        }
+    };
+    let _ = write!(out, ";{}", code);
+}
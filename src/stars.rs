@@ -0,0 +1,63 @@
+use std::f64::consts::TAU;
+
+/// Default seed for the star field, so every session shows the same sky.
+pub const DEFAULT_SEED: u64 = 0xDEAD_BEEF_CAFE;
+
+#[derive(Clone, Debug)]
+pub struct Star {
+    pub x: f64,
+    pub y: f64,
+    pub size: f64,
+    pub phase: f64,
+    pub speed: f64,
+}
+
+/// Simple LCG pseudo-random number generator (no external crate needed).
+struct SimpleRng {
+    state: u64,
+}
+
+impl SimpleRng {
+    fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    fn next(&mut self) -> u64 {
+        self.state = self.state.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+        self.state
+    }
+
+    /// Returns a f64 in [0.0, 1.0)
+    fn next_f64(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Returns a f64 in [lo, hi)
+    fn next_range(&mut self, lo: f64, hi: f64) -> f64 {
+        lo + self.next_f64() * (hi - lo)
+    }
+}
+
+/// Scatter 50 stars in the corner negative space around the watch case.
+pub fn generate_stars(seed: u64) -> Vec<Star> {
+    let mut rng = SimpleRng::new(seed);
+    let mut stars = Vec::new();
+    let bounds = 100.0;
+    let min_radius = 99.0; // must be outside the watch face
+
+    while stars.len() < 50 {
+        let x = rng.next_range(-bounds, bounds);
+        let y = rng.next_range(-bounds, bounds);
+        let dist = (x * x + y * y).sqrt();
+        if dist > min_radius {
+            stars.push(Star {
+                x,
+                y,
+                size: rng.next_range(0.3, 1.2),
+                phase: rng.next_range(0.0, TAU),
+                speed: rng.next_range(0.5, 2.0),
+            });
+        }
+    }
+    stars
+}
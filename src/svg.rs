@@ -0,0 +1,100 @@
+//! Draws a [`Scene`] as an SVG image.
+//!
+//! The scene's ±100 space becomes the image's view box, with y flipped to
+//! run downwards. Strokes are about as wide as a Braille dot on an
+//! 80-column face, and labels use the viewer's monospace font.
+
+use std::f64::consts::PI;
+use std::fmt::Write;
+
+use ratatui::style::Color;
+
+use crate::scene::{self, Item, Scene};
+
+/// Stroke width in scene units.
+const STROKE: f64 = 0.8;
+/// Label font size in scene units, about one terminal row of an 80 × 40 face.
+const FONT_SIZE: f64 = 5.0;
+
+/// The scene as an SVG document `width` pixels square, on a black background.
+pub fn render(scene: &Scene, width: u32) -> String {
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{width}" viewBox="-100 -100 200 200">"#
+    );
+    svg.push_str("<rect x=\"-100\" y=\"-100\" width=\"200\" height=\"200\" fill=\"#000000\"/>\n");
+    let _ = writeln!(
+        svg,
+        r#"<g fill="none" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round">"#,
+        num(STROKE)
+    );
+    for item in &scene.items {
+        let _ = writeln!(svg, "{}", element(item));
+    }
+    svg.push_str("</g>\n</svg>\n");
+    svg
+}
+
+fn element(item: &Item) -> String {
+    match item {
+        Item::Line { x1, y1, x2, y2, color } => format!(
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}" stroke="{}"/>"#,
+            num(*x1), num(-y1), num(*x2), num(-y2), hex(*color)
+        ),
+        Item::Circle { x, y, radius, color } => format!(
+            r#"<circle cx="{}" cy="{}" r="{}" stroke="{}"/>"#,
+            num(*x), num(-y), num(*radius), hex(*color)
+        ),
+        Item::Polygon { points, color } => {
+            let points: Vec<String> = points.iter().map(|&(x, y)| format!("{},{}", num(x), num(-y))).collect();
+            format!(
+                r#"<polygon points="{}" fill="{}" fill-rule="evenodd"/>"#,
+                points.join(" "), hex(*color)
+            )
+        }
+        Item::Disc { x, y, radius, color } => format!(
+            r#"<circle cx="{}" cy="{}" r="{}" fill="{}"/>"#,
+            num(*x), num(-y), num(*radius), hex(*color)
+        ),
+        Item::AnnulusSector { inner, outer, from, to, color } => {
+            let span = to - from;
+            if span >= 2.0 * PI {
+                // A whole ring is one wide stroke
+                return format!(
+                    r#"<circle cx="0" cy="0" r="{}" stroke="{}" stroke-width="{}"/>"#,
+                    num((inner + outer) / 2.0), hex(*color), num(outer - inner)
+                );
+            }
+            // Out along `from`, clockwise round the outer edge, back along the inner
+            let large = u8::from(span > PI);
+            let at = |r: f64, a: f64| format!("{},{}", num(r * a.sin()), num(-r * a.cos()));
+            format!(
+                r#"<path d="M{} L{} A{o},{o} 0 {large} 1 {} L{} A{i},{i} 0 {large} 0 {} Z" fill="{}"/>"#,
+                at(*inner, *from), at(*outer, *from), at(*outer, *to),
+                at(*inner, *to), at(*inner, *from), hex(*color),
+                o = num(*outer), i = num(*inner),
+            )
+        }
+        Item::Label { x, y, text, color } => format!(
+            r#"<text x="{}" y="{}" fill="{}" stroke="none" font-family="monospace" font-size="{}" text-anchor="middle" dominant-baseline="central">{}</text>"#,
+            num(*x), num(-y), hex(*color), num(FONT_SIZE), escape(text)
+        ),
+    }
+}
+
+/// A coordinate to two decimal places, without trailing zeros.
+fn num(v: f64) -> String {
+    let s = format!("{:.2}", v);
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    if s == "-0" { "0".to_string() } else { s.to_string() }
+}
+
+fn hex(color: Color) -> String {
+    let (r, g, b) = scene::rgb(color);
+    format!("#{:02x}{:02x}{:02x}", r, g, b)
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
@@ -0,0 +1,24 @@
+use ratatui::style::Color;
+use super::{BezelStyle, HourIndex, Orientation, WatchTheme};
+
+/// Air-King: a pilot's watch with large upright Arabic numerals.
+pub struct AirKingTheme;
+
+impl WatchTheme for AirKingTheme {
+    fn name(&self) -> &str { "Air-King" }
+
+    fn bezel_color(&self) -> Color { Color::Gray }
+    fn hour_hand_color(&self) -> Color { Color::White }
+    fn minute_hand_color(&self) -> Color { Color::White }
+    fn second_hand_color(&self) -> Color { Color::LightGreen }
+    fn marker_color(&self) -> Color { Color::White }
+    fn logo_color(&self) -> Color { Color::Yellow }
+    fn date_color(&self) -> Color { Color::White }
+
+    fn hour_hand_length(&self) -> f64 { 0.55 }
+    fn minute_hand_length(&self) -> f64 { 0.95 }
+    fn second_hand_length(&self) -> f64 { 1.0 }
+
+    fn bezel_style(&self) -> BezelStyle { BezelStyle::Smooth }
+    fn hour_index(&self) -> HourIndex { HourIndex::Arabic(Orientation::Upright) }
+}
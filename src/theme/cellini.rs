@@ -0,0 +1,35 @@
+use ratatui::style::Color;
+use super::{BezelStyle, HandSet, HandStyle, HourIndex, Orientation, WatchTheme};
+
+/// Cellini: a dress watch with radial Roman numerals, dauphine hands and a plain bezel.
+pub struct CelliniTheme;
+
+impl WatchTheme for CelliniTheme {
+    fn name(&self) -> &str { "Cellini" }
+
+    fn bezel_color(&self) -> Color { Color::Yellow }
+    fn hour_hand_color(&self) -> Color { Color::Yellow }
+    fn minute_hand_color(&self) -> Color { Color::Yellow }
+    fn second_hand_color(&self) -> Color { Color::Gray }
+    fn marker_color(&self) -> Color { Color::White }
+    fn logo_color(&self) -> Color { Color::Yellow }
+    fn date_color(&self) -> Color { Color::White }
+
+    fn hour_hand_length(&self) -> f64 { 0.55 }
+    fn minute_hand_length(&self) -> f64 { 0.90 }
+    fn second_hand_length(&self) -> f64 { 0.95 }
+
+    fn hand_set(&self) -> HandSet {
+        HandSet {
+            hour: HandStyle::Dauphine,
+            minute: HandStyle::Dauphine,
+            second: HandStyle::Needle,
+            ..HandSet::SPORT
+        }
+    }
+
+    fn bezel_style(&self) -> BezelStyle { BezelStyle::Smooth }
+    fn hour_index(&self) -> HourIndex {
+        HourIndex::Roman { orientation: Orientation::Radial, iiii: true }
+    }
+}
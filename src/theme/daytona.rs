@@ -0,0 +1,24 @@
+use ratatui::style::Color;
+use super::{BezelStyle, WatchTheme};
+
+/// Daytona: a chronograph with three sub-dials and a fixed tachymeter bezel.
+pub struct DaytonaTheme;
+
+impl WatchTheme for DaytonaTheme {
+    fn name(&self) -> &str { "Daytona" }
+
+    fn bezel_color(&self) -> Color { Color::Gray }
+    fn hour_hand_color(&self) -> Color { Color::White }
+    fn minute_hand_color(&self) -> Color { Color::White }
+    fn second_hand_color(&self) -> Color { Color::Red }
+    fn marker_color(&self) -> Color { Color::White }
+    fn logo_color(&self) -> Color { Color::Red }
+    fn date_color(&self) -> Color { Color::White }
+
+    fn hour_hand_length(&self) -> f64 { 0.55 }
+    fn minute_hand_length(&self) -> f64 { 0.95 }
+    fn second_hand_length(&self) -> f64 { 1.05 }
+
+    fn bezel_style(&self) -> BezelStyle { BezelStyle::Tachymeter }
+    fn has_chronograph(&self) -> bool { true }
+}
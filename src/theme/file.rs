@@ -0,0 +1,560 @@
+//! Themes described in TOML files, so new faces can ship without touching Rust.
+//!
+//! ```toml
+//! name = "Sea-Dweller"
+//! logo-text = "SEA-DWELLER"  # defaults to RUSTLEX
+//! date-window = true
+//!
+//! [colors]
+//! bezel = "dark-gray"        # named colour
+//! hour-hand = "#f5f5f0"      # RGB hex
+//! minute-hand = [245, 245, 240]  # RGB triple
+//! second-hand = "red"
+//! marker = 156               # 256-colour index
+//! logo = "dark-gray"
+//! date = "white"
+//!
+//! [hands]                    # fractions of the marker-inner radius
+//! hour = 0.5
+//! minute = 1.0
+//! second = 0.95
+//! hour-style = "mercedes"    # also minute-, second- and gmt-style; see below
+//! ```
+//!
+//! Hand styles are `mercedes`, `sword`, `dauphine`, `leaf`, `cathedral`,
+//! `pomme`, `syringe`, `pencil`, `arrow`, `skeleton`, `lollipop` and
+//! `needle`. The default set is mercedes, sword, lollipop and an arrow GMT hand.
+//!
+//! A GMT face adds a 24-hour hand and a two-tone 24-hour bezel:
+//!
+//! ```toml
+//! gmt-hand = true
+//!
+//! [bezel]
+//! style = "gmt"              # "dive" (the default), "gmt", "tachymeter", "world-time" or "smooth"
+//! day = "red"
+//! night = "blue"
+//! clicks = 24                # clicks per turn; the style sets the default
+//! action = "bidirectional"   # or "unidirectional", "friction-lock", "fixed"
+//!
+//! [colors]
+//! gmt-hand = "red"           # optional, defaults to second-hand
+//! ```
+//!
+//! The hour indices default to the dive-watch dots and batons:
+//!
+//! ```toml
+//! [dial]
+//! index = "roman"            # "dots", "arabic", "roman", "breguet" or "sticks"
+//! numerals = "radial"        # or "upright" (the default)
+//! four = "iv"                # Roman four; "iiii" (the default) or "iv"
+//! ```
+//!
+//! `chronograph = true` adds stopwatch sub-dials, usually with
+//! `style = "tachymeter"` in `[bezel]`. `style = "world-time"` puts the
+//! built-in city ring on the bezel and a 24-hour ring, in the `day` and
+//! `night` colours, inside it.
+
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use ratatui::style::Color;
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::Deserialize;
+
+use super::{BezelAction, BezelSpec, BezelStyle, HandSet, HandStyle, HourIndex, Orientation, WatchTheme};
+use crate::font;
+use crate::world_time::{City, CITIES};
+
+/// A theme loaded from a TOML file.
+#[derive(Clone, Debug)]
+pub struct FileTheme {
+    name: String,
+    colors: Colors,
+    hands: Hands,
+    bezel: Bezel,
+    dial: Dial,
+    logo_text: Option<LogoText>,
+    date_window: bool,
+    gmt_hand: bool,
+    chronograph: bool,
+    path: Option<PathBuf>,
+}
+
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct ThemeFile {
+    name: Option<String>,
+    logo_text: Option<LogoText>,
+    #[serde(default)]
+    date_window: bool,
+    #[serde(default)]
+    gmt_hand: bool,
+    #[serde(default)]
+    chronograph: bool,
+    colors: Colors,
+    #[serde(default)]
+    hands: Hands,
+    #[serde(default)]
+    bezel: Bezel,
+    #[serde(default)]
+    dial: Dial,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct Colors {
+    bezel: ThemeColor,
+    hour_hand: ThemeColor,
+    minute_hand: ThemeColor,
+    second_hand: ThemeColor,
+    marker: ThemeColor,
+    logo: ThemeColor,
+    date: ThemeColor,
+    gmt_hand: Option<ThemeColor>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct Bezel {
+    #[serde(default)]
+    style: BezelKind,
+    // GMT insert or 24-hour ring tones, defaulting per style
+    day: Option<ThemeColor>,
+    night: Option<ThemeColor>,
+    // Mechanism, defaulting to the usual one for the style
+    clicks: Option<BezelClicks>,
+    action: Option<Action>,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum BezelKind {
+    #[default]
+    Dive,
+    Gmt,
+    Tachymeter,
+    WorldTime,
+    Smooth,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct Dial {
+    #[serde(default)]
+    index: IndexKind,
+    #[serde(default)]
+    numerals: Numerals,
+    #[serde(default)]
+    four: RomanFour,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum IndexKind {
+    #[default]
+    Dots,
+    Arabic,
+    Roman,
+    Breguet,
+    Sticks,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Numerals {
+    #[default]
+    Upright,
+    Radial,
+}
+
+#[derive(Clone, Copy, Debug, Default, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum RomanFour {
+    #[default]
+    Iiii,
+    Iv,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum Action {
+    Unidirectional,
+    Bidirectional,
+    FrictionLock,
+    Fixed,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(deny_unknown_fields, rename_all = "kebab-case")]
+struct Hands {
+    #[serde(default = "default_hour")]
+    hour: HandLength,
+    #[serde(default = "default_minute")]
+    minute: HandLength,
+    #[serde(default = "default_second")]
+    second: HandLength,
+    // Shapes, defaulting to the sport set
+    hour_style: Option<HandKind>,
+    minute_style: Option<HandKind>,
+    second_style: Option<HandKind>,
+    gmt_style: Option<HandKind>,
+}
+
+#[derive(Clone, Copy, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+enum HandKind {
+    Mercedes,
+    Sword,
+    Dauphine,
+    Leaf,
+    Cathedral,
+    Pomme,
+    Syringe,
+    Pencil,
+    Arrow,
+    Skeleton,
+    Lollipop,
+    Needle,
+}
+
+impl From<HandKind> for HandStyle {
+    fn from(kind: HandKind) -> Self {
+        match kind {
+            HandKind::Mercedes => HandStyle::Mercedes,
+            HandKind::Sword => HandStyle::Sword,
+            HandKind::Dauphine => HandStyle::Dauphine,
+            HandKind::Leaf => HandStyle::Leaf,
+            HandKind::Cathedral => HandStyle::Cathedral,
+            HandKind::Pomme => HandStyle::Pomme,
+            HandKind::Syringe => HandStyle::Syringe,
+            HandKind::Pencil => HandStyle::Pencil,
+            HandKind::Arrow => HandStyle::Arrow,
+            HandKind::Skeleton => HandStyle::Skeleton,
+            HandKind::Lollipop => HandStyle::Lollipop,
+            HandKind::Needle => HandStyle::Needle,
+        }
+    }
+}
+
+// Same defaults as the `WatchTheme` trait
+fn default_hour() -> HandLength { HandLength(0.50) }
+fn default_minute() -> HandLength { HandLength(0.85) }
+fn default_second() -> HandLength { HandLength(0.95) }
+
+impl Default for Hands {
+    fn default() -> Self {
+        Self {
+            hour: default_hour(),
+            minute: default_minute(),
+            second: default_second(),
+            hour_style: None,
+            minute_style: None,
+            second_style: None,
+            gmt_style: None,
+        }
+    }
+}
+
+/// A colour given as a name (`"dark-gray"`), a 256-colour index (`208`),
+/// a hex string (`"#ff8800"`) or an RGB triple (`[255, 136, 0]`).
+#[derive(Clone, Copy, Debug)]
+struct ThemeColor(Color);
+
+impl<'de> Deserialize<'de> for ThemeColor {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ColorVisitor;
+
+        impl<'de> Visitor<'de> for ColorVisitor {
+            type Value = ThemeColor;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a colour name, a 0-255 index, a \"#rrggbb\" string or an [r, g, b] array")
+            }
+
+            fn visit_str<E: de::Error>(self, s: &str) -> Result<ThemeColor, E> {
+                Color::from_str(s)
+                    .map(ThemeColor)
+                    .map_err(|_| E::custom(format!("unknown colour '{}'", s)))
+            }
+
+            fn visit_i64<E: de::Error>(self, i: i64) -> Result<ThemeColor, E> {
+                u8::try_from(i)
+                    .map(|i| ThemeColor(Color::Indexed(i)))
+                    .map_err(|_| E::custom(format!("colour index {} is outside 0-255", i)))
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<ThemeColor, A::Error> {
+                let mut rgb = [0u8; 3];
+                for (i, channel) in rgb.iter_mut().enumerate() {
+                    let value: i64 = seq.next_element()?
+                        .ok_or_else(|| de::Error::invalid_length(i, &"3 channels"))?;
+                    *channel = u8::try_from(value)
+                        .map_err(|_| de::Error::custom(format!("colour channel {} is outside 0-255", value)))?;
+                }
+                if seq.next_element::<de::IgnoredAny>()?.is_some() {
+                    return Err(de::Error::invalid_length(4, &"3 channels"));
+                }
+                Ok(ThemeColor(Color::Rgb(rgb[0], rgb[1], rgb[2])))
+            }
+        }
+
+        deserializer.deserialize_any(ColorVisitor)
+    }
+}
+
+/// A hand length as a fraction of the marker-inner radius.
+#[derive(Clone, Copy, Debug)]
+struct HandLength(f64);
+
+impl<'de> Deserialize<'de> for HandLength {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let length = f64::deserialize(deserializer)?;
+        if !(0.05..=1.2).contains(&length) {
+            return Err(de::Error::custom(format!("hand length {} is outside 0.05-1.2", length)));
+        }
+        Ok(HandLength(length))
+    }
+}
+
+/// Logo text that the dial's stroke font can draw.
+#[derive(Clone, Debug)]
+struct LogoText(String);
+
+impl<'de> Deserialize<'de> for LogoText {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+        if let Some(c) = text.chars().find(|&c| font::glyph(c).is_none()) {
+            return Err(de::Error::custom(format!("the dial font has no '{}'", c)));
+        }
+        if text.chars().count() > 24 {
+            return Err(de::Error::custom("logo text is longer than 24 characters"));
+        }
+        Ok(LogoText(text))
+    }
+}
+
+/// Clicks per full turn of the bezel.
+#[derive(Clone, Copy, Debug)]
+struct BezelClicks(u32);
+
+impl<'de> Deserialize<'de> for BezelClicks {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let clicks = u32::deserialize(deserializer)?;
+        if !(2..=720).contains(&clicks) {
+            return Err(de::Error::custom(format!("{} clicks is outside 2-720", clicks)));
+        }
+        Ok(BezelClicks(clicks))
+    }
+}
+
+/// Why a theme file could not be loaded.
+#[derive(Clone, Debug)]
+pub struct ThemeError {
+    pub path: Option<PathBuf>,
+    /// 1-based line of the offending value, when known.
+    pub line: Option<usize>,
+    /// Dotted key of the offending value, e.g. `colors.bezel`.
+    pub field: Option<String>,
+    pub message: String,
+}
+
+impl fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(path) = &self.path {
+            write!(f, "{}", path.display())?;
+            if let Some(line) = self.line {
+                write!(f, ":{}", line)?;
+            }
+            write!(f, ": ")?;
+        } else if let Some(line) = self.line {
+            write!(f, "line {}: ", line)?;
+        }
+        if let Some(field) = &self.field {
+            write!(f, "{}: ", field)?;
+        }
+        write!(f, "{}", self.message)
+    }
+}
+
+impl std::error::Error for ThemeError {}
+
+impl FileTheme {
+    /// Parse a theme from TOML text. `fallback_name` is used when the file has no `name`.
+    pub fn parse(text: &str, fallback_name: &str) -> Result<Self, ThemeError> {
+        let file: ThemeFile = toml::from_str(text).map_err(|e| {
+            let (line, field) = match e.span() {
+                Some(span) => locate(text, span.start),
+                None => (None, None),
+            };
+            ThemeError { path: None, line, field, message: e.message().to_string() }
+        })?;
+        Ok(Self {
+            name: file.name.unwrap_or_else(|| fallback_name.to_string()),
+            colors: file.colors,
+            hands: file.hands,
+            bezel: file.bezel,
+            dial: file.dial,
+            logo_text: file.logo_text,
+            date_window: file.date_window,
+            gmt_hand: file.gmt_hand,
+            chronograph: file.chronograph,
+            path: None,
+        })
+    }
+
+    /// Load a theme file; the file stem names the theme unless it sets `name`.
+    pub fn load(path: &Path) -> Result<Self, ThemeError> {
+        let with_path = |mut e: ThemeError| {
+            e.path = Some(path.to_path_buf());
+            e
+        };
+        let text = fs::read_to_string(path).map_err(|e| with_path(ThemeError {
+            path: None, line: None, field: None, message: e.to_string(),
+        }))?;
+        let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("Untitled");
+        let mut theme = Self::parse(&text, stem).map_err(with_path)?;
+        theme.path = Some(path.to_path_buf());
+        Ok(theme)
+    }
+
+    /// The file this theme was loaded from, if any.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+}
+
+/// Line number and dotted key path for a byte offset into TOML text.
+/// Best effort: looks for `key =` on the line and the nearest `[table]` above it.
+fn locate(text: &str, offset: usize) -> (Option<usize>, Option<String>) {
+    let offset = offset.min(text.len());
+    let line_no = text[..offset].matches('\n').count();
+    let lines: Vec<&str> = text.lines().collect();
+    let key = lines.get(line_no)
+        .and_then(|line| line.split_once('='))
+        .map(|(key, _)| key.trim().trim_matches('"').to_string())
+        .filter(|key| !key.is_empty() && !key.starts_with('['));
+    let table = lines[..(line_no + 1).min(lines.len())].iter().rev()
+        .map(|line| line.trim())
+        .find(|line| line.starts_with('[') && line.ends_with(']'))
+        .map(|line| line.trim_matches(|c| c == '[' || c == ']').trim().to_string());
+    let field = match (table, key) {
+        (Some(table), Some(key)) => Some(format!("{}.{}", table, key)),
+        (None, Some(key)) => Some(key),
+        (Some(table), None) => Some(table),
+        (None, None) => None,
+    };
+    (Some(line_no + 1), field)
+}
+
+/// Load every `*.toml` theme in the given directories, in order.
+/// Directories that don't exist are skipped; broken files are reported
+/// alongside the themes that loaded.
+pub fn discover(dirs: &[PathBuf]) -> (Vec<FileTheme>, Vec<ThemeError>) {
+    let mut themes = Vec::new();
+    let mut errors = Vec::new();
+    for dir in dirs {
+        let Ok(entries) = fs::read_dir(dir) else { continue };
+        let mut paths: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|e| e.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "toml"))
+            .collect();
+        paths.sort();
+        for path in paths {
+            match FileTheme::load(&path) {
+                Ok(theme) => themes.push(theme),
+                Err(e) => errors.push(e),
+            }
+        }
+    }
+    (themes, errors)
+}
+
+impl WatchTheme for FileTheme {
+    fn name(&self) -> &str { &self.name }
+
+    fn bezel_color(&self) -> Color { self.colors.bezel.0 }
+    fn hour_hand_color(&self) -> Color { self.colors.hour_hand.0 }
+    fn minute_hand_color(&self) -> Color { self.colors.minute_hand.0 }
+    fn second_hand_color(&self) -> Color { self.colors.second_hand.0 }
+    fn marker_color(&self) -> Color { self.colors.marker.0 }
+    fn logo_color(&self) -> Color { self.colors.logo.0 }
+    fn date_color(&self) -> Color { self.colors.date.0 }
+
+    fn hour_hand_length(&self) -> f64 { self.hands.hour.0 }
+    fn minute_hand_length(&self) -> f64 { self.hands.minute.0 }
+    fn second_hand_length(&self) -> f64 { self.hands.second.0 }
+    fn hand_set(&self) -> HandSet {
+        let sport = HandSet::SPORT;
+        HandSet {
+            hour: self.hands.hour_style.map_or(sport.hour, HandStyle::from),
+            minute: self.hands.minute_style.map_or(sport.minute, HandStyle::from),
+            second: self.hands.second_style.map_or(sport.second, HandStyle::from),
+            gmt: self.hands.gmt_style.map_or(sport.gmt, HandStyle::from),
+        }
+    }
+
+    fn logo_text(&self) -> &str {
+        self.logo_text.as_ref().map_or("RUSTLEX", |t| t.0.as_str())
+    }
+
+    fn hour_index(&self) -> HourIndex {
+        let orientation = match self.dial.numerals {
+            Numerals::Upright => Orientation::Upright,
+            Numerals::Radial => Orientation::Radial,
+        };
+        match self.dial.index {
+            IndexKind::Dots => HourIndex::DotsAndBatons,
+            IndexKind::Arabic => HourIndex::Arabic(orientation),
+            IndexKind::Roman => HourIndex::Roman {
+                orientation,
+                iiii: matches!(self.dial.four, RomanFour::Iiii),
+            },
+            IndexKind::Breguet => HourIndex::Breguet(orientation),
+            IndexKind::Sticks => HourIndex::Sticks,
+        }
+    }
+    fn has_date_window(&self) -> bool { self.date_window }
+
+    fn bezel_style(&self) -> BezelStyle {
+        match self.bezel.style {
+            BezelKind::Dive => BezelStyle::Dive,
+            BezelKind::Gmt => BezelStyle::Gmt24 {
+                day: self.bezel.day.map_or(Color::Red, |c| c.0),
+                night: self.bezel.night.map_or(Color::Blue, |c| c.0),
+            },
+            BezelKind::Tachymeter => BezelStyle::Tachymeter,
+            BezelKind::WorldTime => BezelStyle::WorldTime {
+                day: self.bezel.day.map_or(Color::Yellow, |c| c.0),
+                night: self.bezel.night.map_or(Color::Blue, |c| c.0),
+            },
+            BezelKind::Smooth => BezelStyle::Smooth,
+        }
+    }
+    fn world_cities(&self) -> &[City] {
+        match self.bezel.style {
+            BezelKind::WorldTime => CITIES,
+            _ => &[],
+        }
+    }
+    fn bezel(&self) -> BezelSpec {
+        let default = BezelSpec::for_style(self.bezel_style());
+        BezelSpec {
+            clicks: self.bezel.clicks.map_or(default.clicks, |c| c.0),
+            action: match self.bezel.action {
+                Some(Action::Unidirectional) => BezelAction::Unidirectional,
+                Some(Action::Bidirectional) => BezelAction::Bidirectional,
+                Some(Action::FrictionLock) => BezelAction::FrictionLock,
+                Some(Action::Fixed) => BezelAction::Fixed,
+                None => default.action,
+            },
+        }
+    }
+    fn has_gmt_hand(&self) -> bool { self.gmt_hand }
+    fn has_chronograph(&self) -> bool { self.chronograph }
+    fn gmt_hand_color(&self) -> Color {
+        self.colors.gmt_hand.unwrap_or(self.colors.second_hand).0
+    }
+}
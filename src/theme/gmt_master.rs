@@ -0,0 +1,50 @@
+use ratatui::style::Color;
+use super::{BezelStyle, WatchTheme};
+
+/// GMT-Master: a fourth, 24-hour hand read against a two-tone 24-hour bezel.
+pub struct GmtMasterTheme {
+    name: &'static str,
+    day: Color,
+    night: Color,
+    gmt_hand: Color,
+}
+
+impl GmtMasterTheme {
+    /// Red day / blue night insert.
+    pub const PEPSI: Self = Self {
+        name: "GMT-Master",
+        day: Color::Red,
+        night: Color::Blue,
+        gmt_hand: Color::Red,
+    };
+
+    /// Blue day / black night insert. Black would vanish on a dark
+    /// terminal, so the night half is drawn in dark grey.
+    pub const BATMAN: Self = Self {
+        name: "GMT-Master Batman",
+        day: Color::Blue,
+        night: Color::DarkGray,
+        gmt_hand: Color::LightBlue,
+    };
+}
+
+impl WatchTheme for GmtMasterTheme {
+    fn name(&self) -> &str { self.name }
+
+    fn bezel_color(&self) -> Color { Color::DarkGray }
+    fn hour_hand_color(&self) -> Color { Color::White }
+    fn minute_hand_color(&self) -> Color { Color::White }
+    fn second_hand_color(&self) -> Color { Color::White }
+    fn marker_color(&self) -> Color { Color::Green }
+    fn logo_color(&self) -> Color { Color::DarkGray }
+    fn date_color(&self) -> Color { Color::White }
+
+    fn hour_hand_length(&self) -> f64 { 0.50 }
+    fn minute_hand_length(&self) -> f64 { 1.0 }
+    fn second_hand_length(&self) -> f64 { 0.95 }
+
+    fn has_date_window(&self) -> bool { true }
+    fn bezel_style(&self) -> BezelStyle { BezelStyle::Gmt24 { day: self.day, night: self.night } }
+    fn has_gmt_hand(&self) -> bool { true }
+    fn gmt_hand_color(&self) -> Color { self.gmt_hand }
+}
@@ -1,9 +1,190 @@
+pub mod air_king;
+pub mod cellini;
+pub mod daytona;
+pub mod file;
+pub mod gmt_master;
+pub mod registry;
 pub mod submariner;
+pub mod world_time;
+
+use std::f64::consts::{PI, TAU};
 
 use ratatui::style::Color;
 
-/// Defines the visual properties of a watch face.
-#[allow(dead_code)]
+use crate::world_time::City;
+
+/// The insert printed on the rotating bezel.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum BezelStyle {
+    /// 60-minute dive scale: fine minute ticks for the first quarter, numerals every 10.
+    Dive,
+    /// 24-hour scale for reading a second time zone against the GMT hand,
+    /// in two tones for day (06–18) and night (18–06).
+    Gmt24 { day: Color, night: Color },
+    /// Fixed tachymeter scale, graduated in units per hour from 400 down to 60.
+    Tachymeter,
+    /// Fixed ring of city names, with a 24-hour ring inside it that turns once a day,
+    /// in two tones for day (06–18) and night (18–06).
+    WorldTime { day: Color, night: Color },
+    /// Plain polished bezel with no scale, as on a dress watch.
+    Smooth,
+}
+
+/// How the twelve hour positions on the dial are marked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HourIndex {
+    /// Lume dots, batons at 3, 6 and 9 and a triangle at 12, as on a dive watch.
+    DotsAndBatons,
+    /// Applied Arabic numerals 1–12.
+    Arabic(Orientation),
+    /// Roman numerals. `iiii` writes four as IIII rather than IV, as most watch dials do.
+    Roman { orientation: Orientation, iiii: bool },
+    /// Slanted Arabic numerals in the manner of Breguet's dials.
+    Breguet(Orientation),
+    /// Thin sticks, doubled at 12.
+    Sticks,
+}
+
+/// Which way dial numerals face.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Orientation {
+    /// Level, readable without tilting your head.
+    #[default]
+    Upright,
+    /// Turned so each numeral's foot points at the centre.
+    Radial,
+}
+
+/// The shape of one hand. Each shape is drawn along the hand's own length,
+/// so it stretches with `hour_hand_length`, `minute_hand_length` and so on;
+/// widths stay the same.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum HandStyle {
+    /// Baton with a ring near the tip, as on Rolex sports watches.
+    Mercedes,
+    /// Straight blade with a pointed tip.
+    Sword,
+    /// Long faceted kite, ridged down the middle.
+    Dauphine,
+    /// Slim leaf, widest a little below the middle.
+    Leaf,
+    /// Narrow shaft ending in a pointed, traced window.
+    Cathedral,
+    /// Breguet: a fine needle through a hollow "apple" near the tip.
+    Pomme,
+    /// Thin shaft, a wide tube of lume and a needle tip.
+    Syringe,
+    /// Straight baton sharpened to a point.
+    Pencil,
+    /// Needle with an arrowhead, as on a GMT hand.
+    Arrow,
+    /// Open frame with its centre cut away.
+    Skeleton,
+    /// Fine needle with a disc near the tip and a counterweight.
+    Lollipop,
+    /// Fine needle with a short tail.
+    Needle,
+}
+
+/// The hands fitted to a watch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct HandSet {
+    pub hour: HandStyle,
+    pub minute: HandStyle,
+    pub second: HandStyle,
+    /// The 24-hour hand, drawn on themes with a GMT hand.
+    pub gmt: HandStyle,
+}
+
+impl HandSet {
+    /// Mercedes hour, sword minute and lollipop second hands with an arrow GMT hand.
+    pub const SPORT: Self = Self {
+        hour: HandStyle::Mercedes,
+        minute: HandStyle::Sword,
+        second: HandStyle::Lollipop,
+        gmt: HandStyle::Arrow,
+    };
+}
+
+impl Default for HandSet {
+    fn default() -> Self {
+        Self::SPORT
+    }
+}
+
+/// How the bezel turns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BezelAction {
+    /// Ratchets counter-clockwise only, so a knock can only overstate the
+    /// elapsed dive time, never understate it.
+    Unidirectional,
+    /// Ratchets both ways.
+    Bidirectional,
+    /// No ratchet: turns both ways and stays wherever it is left. `clicks`
+    /// only sets how far one scroll step turns it.
+    FrictionLock,
+    /// Does not turn.
+    Fixed,
+}
+
+/// A bezel's mechanism: how many clicks make a full turn, and which way it turns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BezelSpec {
+    pub clicks: u32,
+    pub action: BezelAction,
+}
+
+impl BezelSpec {
+    /// The usual mechanism for a bezel insert: a 120-click unidirectional dive
+    /// bezel, a 24-click bidirectional GMT bezel; anything else is fixed.
+    pub fn for_style(style: BezelStyle) -> Self {
+        match style {
+            BezelStyle::Dive => Self { clicks: 120, action: BezelAction::Unidirectional },
+            BezelStyle::Gmt24 { .. } => Self { clicks: 24, action: BezelAction::Bidirectional },
+            BezelStyle::Tachymeter | BezelStyle::WorldTime { .. } | BezelStyle::Smooth => {
+                Self { clicks: 120, action: BezelAction::Fixed }
+            }
+        }
+    }
+
+    /// Angle of one click, in radians.
+    pub fn click_angle(&self) -> f64 {
+        TAU / self.clicks.max(1) as f64
+    }
+
+    /// Clicks turned by one coarse (keyboard) step: about 1/40 of a turn.
+    pub fn coarse_step(&self) -> i32 {
+        (self.clicks / 40).max(1) as i32
+    }
+
+    /// Snap an angle to the nearest click position. A friction-lock bezel has no detents.
+    pub fn snap(&self, angle: f64) -> f64 {
+        match self.action {
+            BezelAction::FrictionLock => angle,
+            _ => (angle / self.click_angle()).round() * self.click_angle(),
+        }
+    }
+
+    /// The turn that brings a bezel at `offset` round to zero the timer on the
+    /// minute hand. A ratcheting bezel stops on the click just before the hand,
+    /// so the timer starts at zero, and a unidirectional one gets there
+    /// counter-clockwise; the others take the short way round.
+    pub fn turn_to_hand(&self, offset: f64, minute_angle: f64) -> f64 {
+        let target = match self.action {
+            BezelAction::Fixed => return 0.0,
+            BezelAction::FrictionLock => minute_angle,
+            _ => (minute_angle / self.click_angle() + 1e-9).floor() * self.click_angle(),
+        };
+        let clockwise = (target - offset).rem_euclid(TAU);
+        match self.action {
+            BezelAction::Unidirectional if clockwise > 0.0 => clockwise - TAU,
+            BezelAction::Unidirectional => 0.0,
+            _ if clockwise > PI => clockwise - TAU,
+            _ => clockwise,
+        }
+    }
+}
+
 pub trait WatchTheme {
     fn name(&self) -> &str;
 
@@ -21,6 +202,45 @@ pub trait WatchTheme {
     fn minute_hand_length(&self) -> f64 { 0.85 }
     fn second_hand_length(&self) -> f64 { 0.95 }
 
+    /// Text under the crown, drawn in the dial's stroke font.
+    fn logo_text(&self) -> &str { "RUSTLEX" }
+
+    fn hand_set(&self) -> HandSet { HandSet::SPORT }
+
     // Features
+    fn hour_index(&self) -> HourIndex { HourIndex::DotsAndBatons }
     fn has_date_window(&self) -> bool { false }
+    fn bezel_style(&self) -> BezelStyle { BezelStyle::Dive }
+    fn bezel(&self) -> BezelSpec { BezelSpec::for_style(self.bezel_style()) }
+    fn has_gmt_hand(&self) -> bool { false }
+    fn gmt_hand_color(&self) -> Color { self.second_hand_color() }
+    /// Chronograph sub-dials; the centre seconds hand becomes the stopwatch hand.
+    fn has_chronograph(&self) -> bool { false }
+    /// Cities for a [`BezelStyle::WorldTime`] bezel.
+    fn world_cities(&self) -> &[City] { &[] }
+}
+
+/// Look up a built-in theme by name (case-insensitive; spaces, `-` and `_` are interchangeable).
+pub fn builtin(name: &str) -> Option<Box<dyn WatchTheme>> {
+    match normalize_name(name).as_str() {
+        "submariner" => Some(Box::new(submariner::SubmarinerTheme)),
+        "gmt-master" => Some(Box::new(gmt_master::GmtMasterTheme::PEPSI)),
+        "gmt-master-batman" => Some(Box::new(gmt_master::GmtMasterTheme::BATMAN)),
+        "daytona" => Some(Box::new(daytona::DaytonaTheme)),
+        "world-time" => Some(Box::new(world_time::WorldTimeTheme)),
+        "cellini" => Some(Box::new(cellini::CelliniTheme)),
+        "air-king" => Some(Box::new(air_king::AirKingTheme)),
+        _ => None,
+    }
+}
+
+/// Names of all built-in themes, as accepted by [`builtin`].
+pub fn builtin_names() -> &'static [&'static str] {
+    &["submariner", "gmt-master", "gmt-master-batman", "daytona", "world-time", "cellini", "air-king"]
+}
+
+/// Canonical form of a theme name for lookups: lowercase, with spaces and
+/// underscores turned into hyphens, so "GMT-Master Batman" matches `gmt-master-batman`.
+pub fn normalize_name(name: &str) -> String {
+    name.trim().to_ascii_lowercase().replace([' ', '_'], "-")
 }
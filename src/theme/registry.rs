@@ -0,0 +1,140 @@
+use std::path::{Path, PathBuf};
+
+use super::file::{self, FileTheme, ThemeError};
+use super::{builtin, builtin_names, normalize_name, WatchTheme};
+
+/// All themes available to switch between: the built-ins first, then
+/// themes found on the search path.
+pub struct ThemeRegistry {
+    themes: Vec<Entry>,
+    errors: Vec<ThemeError>,
+}
+
+struct Entry {
+    theme: Box<dyn WatchTheme>,
+    path: Option<PathBuf>,
+}
+
+impl ThemeRegistry {
+    /// Only the themes compiled into the crate.
+    pub fn builtin() -> Self {
+        let themes = builtin_names().iter()
+            .filter_map(|name| builtin(name))
+            .map(|theme| Entry { theme, path: None })
+            .collect();
+        Self { themes, errors: Vec::new() }
+    }
+
+    /// The built-ins plus every theme file in `dirs`. A file theme named like
+    /// a built-in takes its place; between files, the first one found wins.
+    /// Files that fail to load are kept in [`errors`](Self::errors).
+    pub fn load(dirs: &[PathBuf]) -> Self {
+        let mut registry = Self::builtin();
+        let (themes, errors) = file::discover(dirs);
+        for theme in themes {
+            match registry.named(theme.name()) {
+                Some(index) if registry.themes[index].path.is_some() => {}
+                _ => {
+                    registry.put_file(theme);
+                }
+            }
+        }
+        registry.errors = errors;
+        registry
+    }
+
+    pub fn len(&self) -> usize {
+        self.themes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.themes.is_empty()
+    }
+
+    /// Panics if `index` is out of bounds.
+    pub fn get(&self, index: usize) -> &dyn WatchTheme {
+        self.themes[index].theme.as_ref()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &dyn WatchTheme> {
+        self.themes.iter().map(|e| e.theme.as_ref())
+    }
+
+    /// The index `step` themes on from `index` (back for a negative step),
+    /// wrapping round the ends.
+    pub fn step(&self, index: usize, step: isize) -> usize {
+        (index as isize + step).rem_euclid(self.themes.len() as isize) as usize
+    }
+
+    /// Theme files on the search path that failed to load.
+    pub fn errors(&self) -> &[ThemeError] {
+        &self.errors
+    }
+
+    /// Add a theme and return its index.
+    pub fn push(&mut self, theme: Box<dyn WatchTheme>) -> usize {
+        self.themes.push(Entry { theme, path: None });
+        self.themes.len() - 1
+    }
+
+    /// Put a file theme in place of the theme with its name, or add it if
+    /// there is none, and return its index.
+    fn put_file(&mut self, theme: FileTheme) -> usize {
+        let index = self.named(theme.name());
+        let path = theme.path().map(Path::to_path_buf);
+        let entry = Entry { theme: Box::new(theme), path };
+        match index {
+            Some(index) => {
+                self.themes[index] = entry;
+                index
+            }
+            None => {
+                self.themes.push(entry);
+                self.themes.len() - 1
+            }
+        }
+    }
+
+    /// Index of the theme with this name, ignoring file stems.
+    fn named(&self, name: &str) -> Option<usize> {
+        let name = normalize_name(name);
+        self.themes.iter().position(|e| normalize_name(e.theme.name()) == name)
+    }
+
+    /// Index of the theme with this name or file stem, compared with [`normalize_name`].
+    pub fn position(&self, name: &str) -> Option<usize> {
+        let name = normalize_name(name);
+        self.themes.iter().position(|e| {
+            normalize_name(e.theme.name()) == name || stem_matches(e.path.as_deref(), &name)
+        })
+    }
+
+    /// Find a theme by name or file stem, or load it from a path to a
+    /// `.toml` file, and return its index. A file named by its path takes
+    /// the place of any theme with the same name.
+    pub fn resolve(&mut self, name: &str) -> Result<usize, ThemeError> {
+        if let Some(index) = self.position(name) {
+            return Ok(index);
+        }
+        let path = Path::new(name);
+        if path.extension().is_some_and(|ext| ext == "toml") && path.is_file() {
+            return Ok(self.put_file(FileTheme::load(path)?));
+        }
+        if let Some(err) = self.errors.iter().find(|e| stem_matches(e.path.as_deref(), name)) {
+            return Err(err.clone());
+        }
+
+        let available: Vec<&str> = self.iter().map(|t| t.name()).collect();
+        Err(ThemeError {
+            path: None,
+            line: None,
+            field: None,
+            message: format!("unknown theme '{}' (available: {})", name, available.join(", ")),
+        })
+    }
+}
+
+fn stem_matches(path: Option<&Path>, name: &str) -> bool {
+    path.and_then(|p| p.file_stem()).and_then(|stem| stem.to_str())
+        .is_some_and(|stem| normalize_name(stem) == normalize_name(name))
+}
@@ -0,0 +1,27 @@
+use ratatui::style::Color;
+use super::{BezelStyle, WatchTheme};
+use crate::world_time::{City, CITIES};
+
+/// World time: 24 cities around the bezel and a day/night 24-hour ring.
+pub struct WorldTimeTheme;
+
+impl WatchTheme for WorldTimeTheme {
+    fn name(&self) -> &str { "World Time" }
+
+    fn bezel_color(&self) -> Color { Color::Gray }
+    fn hour_hand_color(&self) -> Color { Color::Yellow }
+    fn minute_hand_color(&self) -> Color { Color::Yellow }
+    fn second_hand_color(&self) -> Color { Color::Gray }
+    fn marker_color(&self) -> Color { Color::Yellow }
+    fn logo_color(&self) -> Color { Color::Gray }
+    fn date_color(&self) -> Color { Color::White }
+
+    fn hour_hand_length(&self) -> f64 { 0.50 }
+    fn minute_hand_length(&self) -> f64 { 0.85 }
+    fn second_hand_length(&self) -> f64 { 0.90 }
+
+    fn bezel_style(&self) -> BezelStyle {
+        BezelStyle::WorldTime { day: Color::Yellow, night: Color::Blue }
+    }
+    fn world_cities(&self) -> &[City] { CITIES }
+}
@@ -0,0 +1,166 @@
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, TimeDelta, Utc};
+
+/// Where the watch gets its time from.
+///
+/// Sources report an absolute instant; the time zone is applied when the
+/// instant is turned into [`ClockHands`](crate::clock::ClockHands).
+pub trait TimeSource {
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Seconds that pass on this source per real second: 0 for a source that
+    /// stands still. Used to schedule redraws.
+    fn rate(&self) -> f64 {
+        1.0
+    }
+}
+
+impl<T: TimeSource + ?Sized> TimeSource for Box<T> {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+
+    fn rate(&self) -> f64 {
+        (**self).rate()
+    }
+}
+
+impl<T: TimeSource + ?Sized> TimeSource for &T {
+    fn now(&self) -> DateTime<Utc> {
+        (**self).now()
+    }
+
+    fn rate(&self) -> f64 {
+        (**self).rate()
+    }
+}
+
+/// Real time until `source` next reaches a whole second, or `None` if it
+/// stands still. Exactly on a second, that is the next one.
+pub fn until_next_second<T: TimeSource + ?Sized>(source: &T) -> Option<Duration> {
+    until_next_step(source, 1.0)
+}
+
+/// Like [`until_next_second`], for a hand that moves `steps` times a second
+/// in step with the whole seconds.
+pub fn until_next_step<T: TimeSource + ?Sized>(source: &T, steps: f64) -> Option<Duration> {
+    let rate = source.rate();
+    if rate <= 0.0 || steps <= 0.0 {
+        return None;
+    }
+    let into = source.now().timestamp_subsec_nanos().min(999_999_999) as f64 / 1e9;
+    let remaining = (1.0 - (into * steps).fract()) / steps;
+    Some(Duration::from_secs_f64(remaining / rate))
+}
+
+/// The wall clock.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl TimeSource for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+}
+
+/// Always reports the same instant. Useful for snapshots and screenshots.
+#[derive(Clone, Copy, Debug)]
+pub struct FixedClock(pub DateTime<Utc>);
+
+impl TimeSource for FixedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.0
+    }
+
+    fn rate(&self) -> f64 {
+        0.0
+    }
+}
+
+/// Shifts another source by a constant amount.
+#[derive(Clone, Debug)]
+pub struct OffsetClock<T> {
+    inner: T,
+    offset: TimeDelta,
+}
+
+impl<T: TimeSource> OffsetClock<T> {
+    pub fn new(inner: T, offset: TimeDelta) -> Self {
+        Self { inner, offset }
+    }
+}
+
+impl<T: TimeSource> TimeSource for OffsetClock<T> {
+    fn now(&self) -> DateTime<Utc> {
+        self.inner.now() + self.offset
+    }
+
+    fn rate(&self) -> f64 {
+        self.inner.rate()
+    }
+}
+
+/// Runs `rate` times faster than real time from a starting instant,
+/// e.g. a rate of 60.0 turns every real second into a minute.
+#[derive(Clone, Debug)]
+pub struct AcceleratedClock {
+    origin: DateTime<Utc>,
+    started: Instant,
+    rate: f64,
+}
+
+impl AcceleratedClock {
+    pub fn new(origin: DateTime<Utc>, rate: f64) -> Self {
+        Self { origin, started: Instant::now(), rate }
+    }
+}
+
+impl TimeSource for AcceleratedClock {
+    fn now(&self) -> DateTime<Utc> {
+        let simulated = self.started.elapsed().as_secs_f64() * self.rate;
+        let whole = simulated.floor();
+        // Past the ends of the calendar the clock stops there
+        TimeDelta::new(whole as i64, ((simulated - whole) * 1e9) as u32)
+            .and_then(|delta| self.origin.checked_add_signed(delta))
+            .unwrap_or(if simulated < 0.0 { DateTime::<Utc>::MIN_UTC } else { DateTime::<Utc>::MAX_UTC })
+    }
+
+    fn rate(&self) -> f64 {
+        self.rate
+    }
+}
+
+/// Replays a fixed list of instants, one per call to [`TimeSource::now`].
+/// Once the script runs out the last instant is repeated.
+#[derive(Clone, Debug)]
+pub struct ScriptedClock {
+    times: Vec<DateTime<Utc>>,
+    next: Cell<usize>,
+}
+
+impl ScriptedClock {
+    /// Panics if `times` is empty.
+    pub fn new(times: Vec<DateTime<Utc>>) -> Self {
+        assert!(!times.is_empty(), "ScriptedClock needs at least one instant");
+        Self { times, next: Cell::new(0) }
+    }
+
+    /// Build a script of `count` instants spaced `step` apart from `start`.
+    pub fn stepped(start: DateTime<Utc>, step: TimeDelta, count: usize) -> Self {
+        let count = i32::try_from(count.max(1)).unwrap_or(i32::MAX);
+        let times = (0..count).map(|i| start + step * i).collect();
+        Self::new(times)
+    }
+}
+
+impl TimeSource for ScriptedClock {
+    fn now(&self) -> DateTime<Utc> {
+        let i = self.next.get();
+        if i + 1 < self.times.len() {
+            self.next.set(i + 1);
+        }
+        self.times[i]
+    }
+}
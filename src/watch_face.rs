@@ -1,12 +1,20 @@
-use ratatui::Frame;
+use ratatui::buffer::Buffer;
 use ratatui::layout::Rect;
 use ratatui::style::Color;
-use ratatui::symbols::Marker;
-use ratatui::widgets::canvas::{Canvas, Circle, Line, Context};
+use ratatui::symbols::{self, Marker as CanvasMarker};
+use ratatui::widgets::canvas::{Canvas, Circle, Line, Painter, Shape};
+use ratatui::widgets::{StatefulWidget, Widget};
 use std::f64::consts::{PI, TAU};
+use std::time::Duration;
 
-use crate::app::App;
 use crate::clock::{ClockHands, hand_endpoint};
+use crate::fill::{AnnulusSector, Disc, Polygon};
+use crate::font::{self, TextSize};
+use crate::marker::{self, Marker};
+use crate::scene::Scene;
+use crate::stars::{self, generate_stars, Star};
+use crate::theme::{BezelStyle, HandSet, HandStyle, HourIndex, Orientation, WatchTheme};
+use crate::world_time::WorldTime;
 
 const BOUNDS: f64 = 100.0;
 
@@ -21,38 +29,6 @@ const MARKER_OUTER: f64 = 74.0;
 const MARKER_INNER: f64 = 65.0;
 const MARKER_CENTER: f64 = 69.5;  // center of circle dot markers
 
-// ── Letter definitions for RUSTLEX logo ──
-type Segs = &'static [(f64, f64, f64, f64)];
-
-const LETTER_R: Segs = &[
-    (0.0, 0.0, 0.0, 10.0), (0.0, 10.0, 6.0, 10.0),
-    (6.0, 10.0, 6.0, 5.0), (6.0, 5.0, 0.0, 5.0),
-    (0.0, 5.0, 6.0, 0.0),
-];
-const LETTER_U: Segs = &[
-    (0.0, 10.0, 0.0, 0.0), (0.0, 0.0, 6.0, 0.0), (6.0, 0.0, 6.0, 10.0),
-];
-const LETTER_S: Segs = &[
-    (6.0, 10.0, 0.0, 10.0), (0.0, 10.0, 0.0, 5.0),
-    (0.0, 5.0, 6.0, 5.0), (6.0, 5.0, 6.0, 0.0), (6.0, 0.0, 0.0, 0.0),
-];
-const LETTER_T: Segs = &[
-    (0.0, 10.0, 6.0, 10.0), (3.0, 10.0, 3.0, 0.0),
-];
-const LETTER_L: Segs = &[
-    (0.0, 10.0, 0.0, 0.0), (0.0, 0.0, 6.0, 0.0),
-];
-const LETTER_E: Segs = &[
-    (0.0, 0.0, 0.0, 10.0), (0.0, 10.0, 6.0, 10.0),
-    (0.0, 5.0, 4.0, 5.0), (0.0, 0.0, 6.0, 0.0),
-];
-const LETTER_X: Segs = &[
-    (0.0, 10.0, 6.0, 0.0), (0.0, 0.0, 6.0, 10.0),
-];
-const LOGO_LETTERS: [Segs; 7] = [
-    LETTER_R, LETTER_U, LETTER_S, LETTER_T, LETTER_L, LETTER_E, LETTER_X,
-];
-
 // ── Crown shape (centered at origin, 12 wide × 10 tall) ──
 const CROWN_SEGS: &[(f64, f64, f64, f64)] = &[
     // Base
@@ -76,54 +52,39 @@ const CROWN_SEGS: &[(f64, f64, f64, f64)] = &[
 // Crown dot positions (tips of prongs 1, 3, 5)
 const CROWN_DOTS: &[(f64, f64)] = &[(-5.0, 8.0), (0.0, 9.0), (5.0, 8.0)];
 
-// ── 7-segment digit definitions ──
-// [top, top-left, top-right, middle, bottom-left, bottom-right, bottom]
-const DIGITS: [[bool; 7]; 10] = [
-    [true,  true,  true,  false, true,  true,  true],   // 0
-    [false, false, true,  false, false, true,  false],   // 1
-    [true,  false, true,  true,  true,  false, true],    // 2
-    [true,  false, true,  true,  false, true,  true],    // 3
-    [false, true,  true,  true,  false, true,  false],   // 4
-    [true,  true,  false, true,  false, true,  true],    // 5
-    [true,  true,  false, true,  true,  true,  true],    // 6
-    [true,  false, true,  false, false, true,  false],   // 7
-    [true,  true,  true,  true,  true,  true,  true],    // 8
-    [true,  true,  true,  true,  false, true,  true],    // 9
-];
-
-// ── Star snapshot (owned copy for closure capture) ──
-struct StarData {
-    x: f64,
-    y: f64,
-    size: f64,
-    phase: f64,
-    speed: f64,
-}
-
-// ── Theme data + app state (owned, for closure capture) ──
-struct ThemeData {
+// ── Theme data + widget options (owned for closure capture, bar the star field) ──
+struct ThemeData<'a> {
     bezel_color: Color,
     hour_hand_color: Color,
     minute_hand_color: Color,
     second_hand_color: Color,
     marker_color: Color,
     logo_color: Color,
+    logo_text: String,
     date_color: Color,
     hour_hand_length: f64,
     minute_hand_length: f64,
     second_hand_length: f64,
+    hand_set: HandSet,
     has_date_window: bool,
+    hour_index: HourIndex,
+    bezel_style: BezelStyle,
+    has_gmt_hand: bool,
+    gmt_hand_color: Color,
+    has_chronograph: bool,
+    chronograph: f64,
+    label: Option<String>,
+    world: WorldTime,
     bezel_offset: f64,
     lume_mode: bool,
-    smooth_seconds: bool,
     elapsed: f64,
     stars_enabled: bool,
-    stars: Vec<StarData>,
+    stars: &'a [Star],
 }
 
-impl ThemeData {
-    fn from_app(app: &App) -> Self {
-        let theme = app.theme.as_ref();
+impl<'a> ThemeData<'a> {
+    fn from_face(face: &WatchFace, stars: &'a [Star]) -> Self {
+        let theme = face.theme;
         Self {
             bezel_color: theme.bezel_color(),
             hour_hand_color: theme.hour_hand_color(),
@@ -131,19 +92,30 @@ impl ThemeData {
             second_hand_color: theme.second_hand_color(),
             marker_color: theme.marker_color(),
             logo_color: theme.logo_color(),
+            logo_text: theme.logo_text().to_string(),
             date_color: theme.date_color(),
             hour_hand_length: theme.hour_hand_length(),
             minute_hand_length: theme.minute_hand_length(),
             second_hand_length: theme.second_hand_length(),
+            hand_set: theme.hand_set(),
             has_date_window: theme.has_date_window(),
-            bezel_offset: app.bezel_offset,
-            lume_mode: app.lume_mode,
-            smooth_seconds: app.smooth_seconds,
-            elapsed: app.elapsed_secs(),
-            stars_enabled: app.stars_enabled,
-            stars: app.stars.iter().map(|s| StarData {
-                x: s.x, y: s.y, size: s.size, phase: s.phase, speed: s.speed,
-            }).collect(),
+            hour_index: theme.hour_index(),
+            bezel_style: theme.bezel_style(),
+            has_gmt_hand: theme.has_gmt_hand(),
+            gmt_hand_color: theme.gmt_hand_color(),
+            has_chronograph: theme.has_chronograph(),
+            chronograph: face.chronograph.as_secs_f64(),
+            label: face.label.clone(),
+            // Without city positions the 24-hour ring still follows the hands
+            world: face.world.clone().unwrap_or(WorldTime {
+                ring_angle: face.hands.gmt_angle,
+                cities: Vec::new(),
+            }),
+            bezel_offset: face.bezel_offset,
+            lume_mode: face.lume,
+            elapsed: face.elapsed,
+            stars_enabled: face.stars,
+            stars,
         }
     }
 }
@@ -158,44 +130,380 @@ fn rotate_for_clock(x: f64, y: f64, clock_angle: f64) -> (f64, f64) {
     (x * cos_a + y * sin_a, -x * sin_a + y * cos_a)
 }
 
-// ── Main draw ──
-pub fn draw(frame: &mut Frame, area: Rect, app: &App) {
-    let td = ThemeData::from_app(app);
-    let clock = ClockHands::now(td.smooth_seconds);
+/// A Submariner-style analogue watch face widget.
+///
+/// The face is drawn into the whole area it is given; use [`square_area`] to
+/// keep it round on terminals with ~2:1 cells. Render it as a plain [`Widget`]
+/// or, to keep a custom star field across frames, as a [`StatefulWidget`]
+/// with a [`WatchFaceState`].
+#[derive(Clone)]
+pub struct WatchFace<'a> {
+    theme: &'a dyn WatchTheme,
+    hands: ClockHands,
+    bezel_offset: f64,
+    lume: bool,
+    stars: bool,
+    elapsed: f64,
+    chronograph: Duration,
+    label: Option<String>,
+    world: Option<WorldTime>,
+    marker: Option<Marker>,
+}
+
+impl<'a> WatchFace<'a> {
+    /// Create a face for the given theme showing the current local time.
+    pub fn new(theme: &'a dyn WatchTheme) -> Self {
+        Self {
+            theme,
+            hands: ClockHands::now(false),
+            bezel_offset: 0.0,
+            lume: false,
+            stars: false,
+            elapsed: 0.0,
+            chronograph: Duration::ZERO,
+            label: None,
+            world: None,
+            marker: None,
+        }
+    }
+
+    /// Set the hand positions and date shown on the dial.
+    pub fn hands(mut self, hands: ClockHands) -> Self {
+        self.hands = hands;
+        self
+    }
+
+    /// Rotate the bezel by the given angle in radians (clockwise).
+    pub fn bezel_offset(mut self, radians: f64) -> Self {
+        self.bezel_offset = radians;
+        self
+    }
+
+    /// Simulate viewing the watch in darkness: only lume and the seconds hand remain.
+    pub fn lume(mut self, lume: bool) -> Self {
+        self.lume = lume;
+        self
+    }
+
+    /// Show the twinkling star field around the case.
+    pub fn stars(mut self, stars: bool) -> Self {
+        self.stars = stars;
+        self
+    }
+
+    /// Seconds since the animation started, used to twinkle the stars.
+    pub fn elapsed(mut self, secs: f64) -> Self {
+        self.elapsed = secs;
+        self
+    }
+
+    /// Time measured by the chronograph, shown on its centre hand and
+    /// totalizers. Only drawn by themes with a chronograph.
+    pub fn chronograph(mut self, elapsed: Duration) -> Self {
+        self.chronograph = elapsed;
+        self
+    }
+
+    /// Text printed under the logo, such as the city a face shows the time for.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Positions of the city ring and 24-hour ring, see [`WorldTime::at`].
+    /// Only drawn by themes with a world-time bezel.
+    pub fn world_time(mut self, world: WorldTime) -> Self {
+        self.world = Some(world);
+        self
+    }
+
+    /// Force a canvas marker instead of picking one from the area size.
+    /// Takes ratatui's markers as well as [`Marker::Quadrant`].
+    pub fn marker(mut self, marker: impl Into<Marker>) -> Self {
+        self.marker = Some(marker.into());
+        self
+    }
+
+    /// Record the whole face as geometry, for drawing it somewhere other than
+    /// a terminal. `stars` is the star field, see [`WatchFaceState::stars`].
+    pub fn scene(&self, stars: &[Star]) -> Scene {
+        let td = ThemeData::from_face(self, stars);
+        let mut scene = star_scene(&td);
+        scene.extend(static_scene(&td, self.hands.date_day));
+        scene.extend(moving_scene(&td, &self.hands));
+        scene
+    }
+}
+
+/// Persistent state for a [`WatchFace`] rendered as a [`StatefulWidget`].
+///
+/// Besides the star field it caches the face's static layers, so a frame in
+/// which only the hands and stars move does not redraw the bezel and dial.
+pub struct WatchFaceState {
+    pub stars: Vec<Star>,
+    layers: Vec<StaticLayer>,
+}
+
+impl WatchFaceState {
+    /// Create a state whose star field is generated from the given seed.
+    pub fn with_seed(seed: u64) -> Self {
+        Self { stars: generate_stars(seed), layers: Vec::new() }
+    }
+}
+
+impl Default for WatchFaceState {
+    fn default() -> Self {
+        Self::with_seed(stars::DEFAULT_SEED)
+    }
+}
+
+impl Widget for WatchFace<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        let mut state = if self.stars {
+            WatchFaceState::default()
+        } else {
+            WatchFaceState { stars: Vec::new(), layers: Vec::new() }
+        };
+        StatefulWidget::render(self, area, buf, &mut state);
+    }
+}
+
+impl StatefulWidget for WatchFace<'_> {
+    type State = WatchFaceState;
+
+    fn render(self, area: Rect, buf: &mut Buffer, state: &mut Self::State) {
+        let td = ThemeData::from_face(&self, &state.stars);
+        let date_day = self.hands.date_day;
+
+        let marker = match self.marker {
+            Some(marker) => marker,
+            None if area.width < 40 => Marker::HalfBlock,
+            None => Marker::Braille,
+        };
+
+        // Canvas units per terminal cell, for centering printed text
+        let cell_width = 2.0 * BOUNDS / area.width.max(1) as f64;
+
+        let key = LayerKey::new(&td, area, marker.canvas(), date_day);
+        let index = match state.layers.iter().position(|layer| layer.key == key) {
+            Some(index) => index,
+            None => {
+                if state.layers.len() == LAYER_CACHE_SIZE {
+                    state.layers.remove(0);
+                }
+                let points = rasterise_static(&static_scene(&td, date_day), area, marker.canvas());
+                state.layers.push(StaticLayer { key, points });
+                state.layers.len() - 1
+            }
+        };
+        let static_layer = &state.layers[index].points;
+        let stars = star_scene(&td);
+        let moving = moving_scene(&td, &self.hands);
+
+        let canvas = Canvas::default()
+            .x_bounds([-BOUNDS, BOUNDS])
+            .y_bounds([-BOUNDS, BOUNDS])
+            .marker(marker.canvas())
+            .background_color(Color::Reset)
+            .paint(move |ctx| {
+                stars.paint(ctx, cell_width);
+                ctx.draw(&Raster(static_layer));
+                moving.paint(ctx, cell_width);
+            });
+
+        canvas.render(area, buf);
+        if marker == Marker::Quadrant {
+            marker::fold_quadrants(buf, area);
+        }
+    }
+}
+
+/// The star field, drawn under everything else.
+fn star_scene(td: &ThemeData) -> Scene {
+    let mut scene = Scene::default();
+    paint_stars(&mut scene, td);
+    scene
+}
 
-    let marker = if area.width < 40 {
-        Marker::HalfBlock
+/// The layers that only change when the bezel turns or the date does.
+fn static_scene(td: &ThemeData, date_day: u32) -> Scene {
+    let mut scene = Scene::default();
+    paint_bezel(&mut scene, td);
+    paint_chapter_ring(&mut scene, td);
+    paint_hour_markers(&mut scene, td);
+    paint_crown(&mut scene, td);
+    paint_logo(&mut scene, td);
+    paint_date_window(&mut scene, td, date_day);
+    scene
+}
+
+/// The 24-hour ring, label, sub-dials and hands, redrawn every frame.
+fn moving_scene(td: &ThemeData, clock: &ClockHands) -> Scene {
+    // On a chronograph the centre seconds hand is the stopwatch hand
+    let hands = if td.has_chronograph {
+        ClockHands { second_angle: (td.chronograph % 60.0) / 60.0 * TAU, ..*clock }
     } else {
-        Marker::Braille
+        *clock
     };
+    let mut scene = Scene::default();
+    paint_24h_ring(&mut scene, td);
+    paint_label(&mut scene, td);
+    if td.has_chronograph {
+        paint_subdials(&mut scene, td, clock);
+    }
+    paint_hands(&mut scene, td, &hands);
+    paint_center_dot(&mut scene, td);
+    scene
+}
+
+// ══════════════════════════════════════════════════════════════
+// LAYER CACHE — the parts of the face that only move when the bezel
+// turns or the date changes, rasterised once and replayed every frame
+// ══════════════════════════════════════════════════════════════
+const LAYER_CACHE_SIZE: usize = 8; // enough for a world clock grid of distinct faces
+
+struct StaticLayer {
+    key: LayerKey,
+    /// Lit grid points with their colours
+    points: Vec<(usize, usize, Color)>,
+}
 
-    let canvas = Canvas::default()
+/// Everything the static layers depend on: when any of it changes they
+/// are rasterised again.
+#[derive(PartialEq)]
+struct LayerKey {
+    size: (u16, u16),
+    marker: CanvasMarker,
+    bezel_color: Color,
+    marker_color: Color,
+    logo_color: Color,
+    logo_text: String,
+    date_color: Color,
+    has_date_window: bool,
+    hour_index: HourIndex,
+    bezel_style: BezelStyle,
+    bezel_offset: f64,
+    lume_mode: bool,
+    date_day: u32,
+    cities: Vec<(&'static str, f64)>,
+}
+
+impl LayerKey {
+    fn new(td: &ThemeData, area: Rect, marker: CanvasMarker, date_day: u32) -> Self {
+        Self {
+            size: (area.width, area.height),
+            marker,
+            bezel_color: td.bezel_color,
+            marker_color: td.marker_color,
+            logo_color: td.logo_color,
+            logo_text: td.logo_text.clone(),
+            date_color: td.date_color,
+            has_date_window: td.has_date_window,
+            hour_index: td.hour_index,
+            bezel_style: td.bezel_style,
+            bezel_offset: td.bezel_offset,
+            lume_mode: td.lume_mode,
+            date_day,
+            cities: td.world.cities.clone(),
+        }
+    }
+}
+
+/// Draw the static layers on their own canvas and read the lit points back
+/// out of the cells, so they can be painted into the full canvas as they were.
+fn rasterise_static(scene: &Scene, area: Rect, marker: CanvasMarker) -> Vec<(usize, usize, Color)> {
+    let area = Rect::new(0, 0, area.width, area.height);
+    let mut buf = Buffer::empty(area);
+    Canvas::default()
         .x_bounds([-BOUNDS, BOUNDS])
         .y_bounds([-BOUNDS, BOUNDS])
         .marker(marker)
-        .background_color(Color::Reset)
-        .paint(move |ctx| {
-            paint_stars(ctx, &td);
-            paint_bezel(ctx, &td);
-            paint_chapter_ring(ctx, &td);
-            paint_hour_markers(ctx, &td);
-            paint_crown(ctx, &td);
-            paint_logo(ctx, &td);
-            paint_date_window(ctx, &td, clock.date_day);
-            paint_hands(ctx, &td, &clock);
-            paint_center_dot(ctx, &td);
-        });
+        // The static layers print no labels
+        .paint(|ctx| scene.paint(ctx, 0.0))
+        .render(area, &mut buf);
+
+    let mut points = Vec::new();
+    for y in 0..area.height {
+        for x in 0..area.width {
+            let cell = &buf[(x, y)];
+            let Some(ch) = cell.symbol().chars().next() else { continue };
+            let (col, row) = (x as usize, y as usize);
+            match marker {
+                CanvasMarker::Braille => {
+                    let bits = (ch as u32).wrapping_sub(0x2800);
+                    if bits > 0xff { continue; }
+                    for (dy, dots) in symbols::braille::DOTS.iter().enumerate() {
+                        for (dx, &dot) in dots.iter().enumerate() {
+                            if bits & dot as u32 != 0 {
+                                points.push((col * 2 + dx, row * 4 + dy, cell.fg));
+                            }
+                        }
+                    }
+                }
+                // Each cell is an upper and a lower pixel, the lower one in
+                // the background colour when they differ
+                CanvasMarker::HalfBlock => match ch {
+                    symbols::half_block::FULL => {
+                        points.push((col, row * 2, cell.fg));
+                        points.push((col, row * 2 + 1, cell.fg));
+                    }
+                    symbols::half_block::UPPER => {
+                        points.push((col, row * 2, cell.fg));
+                        if cell.bg != Color::Reset {
+                            points.push((col, row * 2 + 1, cell.bg));
+                        }
+                    }
+                    symbols::half_block::LOWER => points.push((col, row * 2 + 1, cell.fg)),
+                    _ => {}
+                },
+                _ => {
+                    if ch != ' ' {
+                        points.push((col, row, cell.fg));
+                    }
+                }
+            }
+        }
+    }
+    points
+}
 
-    frame.render_widget(canvas, area);
+/// Grid points painted straight into the canvas, see [`rasterise_static`].
+struct Raster<'a>(&'a [(usize, usize, Color)]);
+
+impl Shape for Raster<'_> {
+    fn draw(&self, painter: &mut Painter) {
+        for &(x, y, color) in self.0 {
+            painter.paint(x, y, color);
+        }
+    }
+}
+
+/// Compute the largest visually-square Rect centered in the available area.
+/// Terminal cells are ~2:1 (height:width in pixels), so we need
+/// rect_width = rect_height * 2 in cell units for a visual square.
+pub fn square_area(area: Rect) -> Rect {
+    let max_w = area.width;
+    let max_h = area.height;
+
+    let (w, h) = if max_w >= max_h * 2 {
+        (max_h * 2, max_h)
+    } else {
+        (max_w, max_w / 2)
+    };
+
+    let x = area.x + (max_w.saturating_sub(w)) / 2;
+    let y = area.y + (max_h.saturating_sub(h)) / 2;
+
+    Rect::new(x, y, w, h)
 }
 
 // ══════════════════════════════════════════════════════════════
 // STARS — twinkling background particles in the corner negative space
 // ══════════════════════════════════════════════════════════════
-fn paint_stars(ctx: &mut Context, td: &ThemeData) {
+fn paint_stars(scene: &mut Scene, td: &ThemeData) {
     if !td.stars_enabled { return; }
     let elapsed = td.elapsed;
-    for star in &td.stars {
+    for star in td.stars {
         let val = (elapsed * star.speed + star.phase).sin();
         let color = if val > 0.5 {
             Color::White
@@ -204,24 +512,40 @@ fn paint_stars(ctx: &mut Context, td: &ThemeData) {
         } else {
             Color::DarkGray
         };
-        ctx.draw(&Circle { x: star.x, y: star.y, radius: star.size, color });
+        scene.draw(&Circle { x: star.x, y: star.y, radius: star.size, color });
     }
 }
 
 // ══════════════════════════════════════════════════════════════
 // BEZEL — outer rotating dive bezel with triangle, numbers, ticks
 // ══════════════════════════════════════════════════════════════
-fn paint_bezel(ctx: &mut Context, td: &ThemeData) {
+fn paint_bezel(scene: &mut Scene, td: &ThemeData) {
     if td.lume_mode { return; }
     let bc = td.bezel_color;
-    let bo = td.bezel_offset; // angular offset from scrollwheel
 
     // Outer and inner bezel rings (circles don't rotate)
-    ctx.draw(&Circle { x: 0.0, y: 0.0, radius: CASE_EDGE, color: bc });
-    ctx.draw(&Circle { x: 0.0, y: 0.0, radius: BEZEL_OUTER, color: bc });
-    ctx.draw(&Circle { x: 0.0, y: 0.0, radius: BEZEL_INNER, color: bc });
+    scene.draw(&Circle { x: 0.0, y: 0.0, radius: CASE_EDGE, color: bc });
+    scene.draw(&Circle { x: 0.0, y: 0.0, radius: BEZEL_OUTER, color: bc });
+    scene.draw(&Circle { x: 0.0, y: 0.0, radius: BEZEL_INNER, color: bc });
 
-    // ── Bezel triangle at 12 o'clock (the pip / zero marker) ──
+    match td.bezel_style {
+        BezelStyle::Dive => {
+            paint_bezel_pip(scene, td);
+            paint_dive_scale(scene, td);
+        }
+        BezelStyle::Gmt24 { day, night } => {
+            paint_bezel_pip(scene, td);
+            paint_gmt_scale(scene, td, day, night);
+        }
+        BezelStyle::Tachymeter => paint_tachymeter_scale(scene, td),
+        BezelStyle::WorldTime { .. } => paint_city_ring(scene, td),
+        BezelStyle::Smooth => {}
+    }
+}
+
+/// Triangle with a luminous pip at the bezel's zero position.
+fn paint_bezel_pip(scene: &mut Scene, td: &ThemeData) {
+    let bo = td.bezel_offset;
     let tri_inner = 83.0;
     let tri_outer = 94.0;
     let tri_spread = 0.04;
@@ -229,12 +553,24 @@ fn paint_bezel(ctx: &mut Context, td: &ThemeData) {
     let (tx, ty) = hand_endpoint(angle_12, tri_outer);
     let (lx, ly) = hand_endpoint(angle_12 - tri_spread, tri_inner);
     let (rx, ry) = hand_endpoint(angle_12 + tri_spread, tri_inner);
-    ctx.draw(&Line { x1: lx, y1: ly, x2: tx, y2: ty, color: td.marker_color });
-    ctx.draw(&Line { x1: rx, y1: ry, x2: tx, y2: ty, color: td.marker_color });
-    ctx.draw(&Line { x1: lx, y1: ly, x2: rx, y2: ry, color: td.marker_color });
+    scene.draw(&Line { x1: lx, y1: ly, x2: tx, y2: ty, color: td.marker_color });
+    scene.draw(&Line { x1: rx, y1: ry, x2: tx, y2: ty, color: td.marker_color });
+    scene.draw(&Line { x1: lx, y1: ly, x2: rx, y2: ry, color: td.marker_color });
     // Luminous pip circle inside the triangle
     let (px, py) = hand_endpoint(angle_12, 88.0);
-    ctx.draw(&Circle { x: px, y: py, radius: 1.5, color: td.marker_color });
+    scene.draw(&Circle { x: px, y: py, radius: 1.5, color: td.marker_color });
+}
+
+/// 60-minute dive scale.
+fn paint_dive_scale(scene: &mut Scene, td: &ThemeData) {
+    let bc = td.bezel_color;
+    let bo = td.bezel_offset;
+
+    // Solid insert along both edges, leaving the numerals a clear track as
+    // on the GMT scale
+    for (inner, outer) in [(BEZEL_OUTER - 3.0, BEZEL_OUTER), (BEZEL_INNER, BEZEL_INNER + 3.0)] {
+        scene.draw(&AnnulusSector { inner, outer, from: 0.0, to: TAU, color: bc });
+    }
 
     // ── Bezel tick marks ──
     // Minutes 0-15: individual minute ticks (fine graduation)
@@ -254,12 +590,13 @@ fn paint_bezel(ctx: &mut Context, td: &ThemeData) {
             // Bold rectangular tick at 5-minute positions
             let tick_h = BEZEL_OUTER - 82.0;
             let center_r = (BEZEL_OUTER + 82.0) / 2.0;
-            draw_rotated_rect(ctx, angle, center_r, 2.5, tick_h, td.marker_color);
+            draw_rotated_rect(scene, angle, center_r, 2.5, tick_h, td.marker_color);
         } else {
-            // Fine line tick (minutes 1-15 only)
+            // Fine line tick (minutes 1-15 only), in the marker colour so it
+            // stands out across the insert
             let (x1, y1) = hand_endpoint(angle, 84.0);
             let (x2, y2) = hand_endpoint(angle, BEZEL_OUTER);
-            ctx.draw(&Line { x1, y1, x2, y2, color: bc });
+            scene.draw(&Line { x1, y1, x2, y2, color: td.marker_color });
         }
     }
 
@@ -272,82 +609,287 @@ fn paint_bezel(ctx: &mut Context, td: &ThemeData) {
         (50, bo + 50.0 / 60.0 * TAU),
     ];
     for &(number, clock_angle) in &bezel_numbers {
-        draw_bezel_number(ctx, number, clock_angle, BEZEL_NUM_R, td.marker_color);
+        draw_bezel_number(scene, number, clock_angle, BEZEL_NUM_R, td.marker_color);
     }
 }
 
-/// Draw a two-digit number on the bezel, rotated to face outward.
-fn draw_bezel_number(ctx: &mut Context, number: u32, clock_angle: f64, radius: f64, color: Color) {
-    let tens = number / 10;
-    let ones = number % 10;
+/// 24-hour GMT scale: two-tone day/night insert with even-hour numerals.
+fn paint_gmt_scale(scene: &mut Scene, td: &ThemeData, day: Color, night: Color) {
+    let bo = td.bezel_offset;
+    let tone = |hour: f64| if (6.0..18.0).contains(&hour) { day } else { night };
 
-    let dw = 4.5;   // digit width
-    let dh = 7.0;   // digit height
-    let gap = 2.0;  // gap between digits
-    let total_w = 2.0 * dw + gap;
+    // Two-tone insert, split at 06 and 18: solid bands along both edges,
+    // leaving the numerals on a clear track between them
+    for (from, to) in [(0.0, 6.0), (6.0, 18.0), (18.0, 24.0)] {
+        let color = tone(from);
+        let a1 = bo + from * TAU / 24.0;
+        let a2 = bo + to * TAU / 24.0;
+        for (inner, outer) in [(BEZEL_OUTER - 3.0, BEZEL_OUTER), (BEZEL_INNER, BEZEL_INNER + 3.0)] {
+            scene.draw(&AnnulusSector { inner, outer, from: a1, to: a2, color });
+        }
+    }
 
-    // Center position on bezel
-    let (bx, by) = hand_endpoint(clock_angle, radius);
+    for hour in 1..24 {
+        let angle = bo + hour as f64 * TAU / 24.0;
+        let color = tone(hour as f64);
+        if hour % 2 == 0 {
+            draw_bezel_number(scene, hour, angle, BEZEL_NUM_R, color);
+        } else {
+            // Odd hours: short bold tick
+            draw_rotated_rect(scene, angle, BEZEL_NUM_R, 2.0, 6.0, color);
+        }
+    }
+}
+
+/// Tachymeter scale: a speed in units per hour, read off where the chronograph
+/// seconds hand stops after one unit. Fixed — it ignores the bezel offset.
+fn paint_tachymeter_scale(scene: &mut Scene, td: &ThemeData) {
+    // A unit timed at `t` seconds reads 3600 / t, so `value` sits at 3600 / value seconds
+    let angle = |value: u32| (3600.0 / value as f64) / 60.0 * TAU;
 
-    // Draw tens digit (offset left of center)
-    draw_digit_rotated(ctx, tens, -(total_w / 2.0), -dh / 2.0, dw, dh, bx, by, clock_angle, color);
-    // Draw ones digit (offset right of center)
-    draw_digit_rotated(ctx, ones, gap / 2.0, -dh / 2.0, dw, dh, bx, by, clock_angle, color);
+    let graduations = (60..100).step_by(5)
+        .chain((100..200).step_by(10))
+        .chain((200..300).step_by(25))
+        .chain((300..=400).step_by(50));
+    for value in graduations {
+        let (x1, y1) = hand_endpoint(angle(value), BEZEL_OUTER - 4.0);
+        let (x2, y2) = hand_endpoint(angle(value), BEZEL_OUTER);
+        scene.draw(&Line { x1, y1, x2, y2, color: td.bezel_color });
+    }
+
+    for value in [400, 300, 200, 150, 120, 100, 90, 80, 70] {
+        draw_bezel_number(scene, value, angle(value), BEZEL_NUM_R - 2.0, td.marker_color);
+    }
+}
+
+/// World-time city ring: each city's name at its offset from home, which
+/// sits at 12 o'clock. Cities that currently share an offset are stacked.
+fn paint_city_ring(scene: &mut Scene, td: &ThemeData) {
+    // Longest name that fits in one 15° slot at full height
+    let slot = TAU / 24.0 * BEZEL_NUM_R;
+    let band = BEZEL_OUTER - BEZEL_INNER - 4.0;
+
+    let mut groups: Vec<(f64, Vec<&str>)> = Vec::new();
+    for &(name, angle) in &td.world.cities {
+        match groups.iter_mut().find(|(a, _)| (a - angle).abs() < 1e-6) {
+            Some((_, names)) => names.push(name),
+            None => groups.push((angle, vec![name])),
+        }
+    }
+
+    for (angle, names) in groups {
+        let row = band / names.len() as f64;
+        let upright = angle.cos() < 0.0;
+        for (i, name) in names.iter().enumerate() {
+            let height = (row * 0.8).min(4.5).min(slot / TextSize::of_height(1.0).measure(name));
+            // First name on top as read: outermost on the upper half, innermost on the lower
+            let offset = (i as f64 + 0.5) * row - band / 2.0;
+            let radius = BEZEL_NUM_R + if upright { offset } else { -offset };
+            draw_text_arc(scene, name, angle, radius, TextSize::of_height(height), td.marker_color);
+        }
+    }
 }
 
-/// Draw a 7-segment digit in local coordinates, rotated by clock_angle, translated to (cx, cy).
-fn draw_digit_rotated(
-    ctx: &mut Context, digit: u32,
-    lx: f64, ly: f64, w: f64, h: f64,
-    cx: f64, cy: f64, clock_angle: f64, color: Color,
+/// Draw text along a circle of `radius`, centred on `clock_angle`, with
+/// glyphs vertically centred on the circle. Text on the lower half of the
+/// dial runs the other way round so it is never upside down.
+fn draw_text_arc(scene: &mut Scene, text: &str, clock_angle: f64, radius: f64, size: TextSize, color: Color) {
+    let sx = size.width / font::GLYPH_W;
+    let sy = size.height / font::GLYPH_H;
+    let upright = clock_angle.cos() < 0.0;
+    let (direction, flip) = if upright { (-1.0, PI) } else { (1.0, 0.0) };
+
+    let mut along = -size.measure(text) / 2.0;
+    for segs in text.chars().filter_map(font::glyph) {
+        let angle = clock_angle + direction * (along + size.width / 2.0) / radius;
+        let (cx, cy) = hand_endpoint(angle, radius);
+        for &(x1, y1, x2, y2) in segs {
+            let local = |x: f64, y: f64| {
+                rotate_for_clock((x - font::GLYPH_W / 2.0) * sx, (y - font::GLYPH_H / 2.0) * sy, angle + flip)
+            };
+            let (rx1, ry1) = local(x1, y1);
+            let (rx2, ry2) = local(x2, y2);
+            scene.draw(&Line { x1: cx + rx1, y1: cy + ry1, x2: cx + rx2, y2: cy + ry2, color });
+        }
+        along += size.advance();
+    }
+}
+
+/// Draw text centred on (`cx`, `cy`), turned so its top faces `clock_angle`
+/// (0 keeps it level). `slant` shears the glyphs to the right, as italics.
+#[allow(clippy::too_many_arguments)]
+fn draw_text_rotated(
+    scene: &mut Scene, text: &str, cx: f64, cy: f64,
+    size: TextSize, clock_angle: f64, slant: f64, color: Color,
 ) {
-    if digit > 9 { return; }
-    let segs = DIGITS[digit as usize];
-    let hh = h / 2.0;
-
-    // 7 segments in local coordinates (relative to digit box at lx, ly)
-    let local_lines: [(f64, f64, f64, f64); 7] = [
-        (lx, ly + h, lx + w, ly + h),           // top
-        (lx, ly + h, lx, ly + hh),              // top-left
-        (lx + w, ly + h, lx + w, ly + hh),      // top-right
-        (lx, ly + hh, lx + w, ly + hh),         // middle
-        (lx, ly + hh, lx, ly),                  // bottom-left
-        (lx + w, ly + hh, lx + w, ly),          // bottom-right
-        (lx, ly, lx + w, ly),                   // bottom
-    ];
+    let sx = size.width / font::GLYPH_W;
+    let sy = size.height / font::GLYPH_H;
+    let mut ox = -size.measure(text) / 2.0;
+    for segs in text.chars().filter_map(font::glyph) {
+        let local = |x: f64, y: f64| {
+            let y = y * sy - size.height / 2.0;
+            rotate_for_clock(ox + x * sx + y * slant, y, clock_angle)
+        };
+        for &(x1, y1, x2, y2) in segs {
+            let (rx1, ry1) = local(x1, y1);
+            let (rx2, ry2) = local(x2, y2);
+            scene.draw(&Line { x1: cx + rx1, y1: cy + ry1, x2: cx + rx2, y2: cy + ry2, color });
+        }
+        ox += size.advance();
+    }
+}
 
-    for (i, &(x1, y1, x2, y2)) in local_lines.iter().enumerate() {
-        if segs[i] {
-            let (rx1, ry1) = rotate_for_clock(x1, y1, clock_angle);
-            let (rx2, ry2) = rotate_for_clock(x2, y2, clock_angle);
-            ctx.draw(&Line {
-                x1: cx + rx1, y1: cy + ry1,
-                x2: cx + rx2, y2: cy + ry2,
+/// Draw a line of text centred on `x`, with its baseline at `y`.
+fn draw_text(scene: &mut Scene, text: &str, x: f64, y: f64, size: TextSize, color: Color) {
+    let sx = size.width / font::GLYPH_W;
+    let sy = size.height / font::GLYPH_H;
+    let mut ox = x - size.measure(text) / 2.0;
+    for segs in text.chars().filter_map(font::glyph) {
+        for &(x1, y1, x2, y2) in segs {
+            scene.draw(&Line {
+                x1: ox + x1 * sx, y1: y + y1 * sy,
+                x2: ox + x2 * sx, y2: y + y2 * sy,
                 color,
             });
         }
+        ox += size.advance();
+    }
+}
+
+/// Draw an arc of radius `r` from clock angle `from` to `to` (clockwise).
+fn draw_arc(scene: &mut Scene, r: f64, from: f64, to: f64, color: Color) {
+    let steps = (((to - from) / TAU * 180.0).ceil() as usize).max(1);
+    let step = (to - from) / steps as f64;
+    for i in 0..steps {
+        let (x1, y1) = hand_endpoint(from + step * i as f64, r);
+        let (x2, y2) = hand_endpoint(from + step * (i + 1) as f64, r);
+        scene.draw(&Line { x1, y1, x2, y2, color });
     }
 }
 
+/// Size of the bezel numerals; the 24-hour ring scales them down.
+const RING_SIZE: TextSize = TextSize { width: 4.5, height: 7.0, gap: 2.0 };
+
+/// Draw a number on the bezel, rotated to face outward.
+fn draw_bezel_number(scene: &mut Scene, number: u32, clock_angle: f64, radius: f64, color: Color) {
+    draw_ring_number(scene, number, clock_angle, radius, RING_SIZE.height, color);
+}
+
+/// Draw a number `height` units tall on a ring, rotated to face outward.
+fn draw_ring_number(scene: &mut Scene, number: u32, clock_angle: f64, radius: f64, height: f64, color: Color) {
+    let (bx, by) = hand_endpoint(clock_angle, radius);
+    let size = RING_SIZE.scaled(height / RING_SIZE.height);
+    draw_text_rotated(scene, &number.to_string(), bx, by, size, clock_angle, 0.0, color);
+}
+
 // ══════════════════════════════════════════════════════════════
 // CHAPTER RING — fine minute tick track between bezel and dial
 // ══════════════════════════════════════════════════════════════
-fn paint_chapter_ring(ctx: &mut Context, td: &ThemeData) {
-    if td.lume_mode { return; }
+fn paint_chapter_ring(scene: &mut Scene, td: &ThemeData) {
+    if td.lume_mode || matches!(td.bezel_style, BezelStyle::WorldTime { .. }) { return; }
     for i in 0..60 {
         let angle = (i as f64) * TAU / 60.0;
         let inner_r = if i % 5 == 0 { CHAPTER_INNER - 2.5 } else { CHAPTER_INNER };
         let (x1, y1) = hand_endpoint(angle, inner_r);
         let (x2, y2) = hand_endpoint(angle, CHAPTER_OUTER);
-        ctx.draw(&Line { x1, y1, x2, y2, color: td.bezel_color });
+        scene.draw(&Line { x1, y1, x2, y2, color: td.bezel_color });
+    }
+}
+
+/// World-time 24-hour ring in place of the chapter ring. It turns once a day,
+/// counting up counter-clockwise, so the hour beside each city is its local time.
+fn paint_24h_ring(scene: &mut Scene, td: &ThemeData) {
+    if td.lume_mode { return; }
+    let BezelStyle::WorldTime { day, night } = td.bezel_style else { return };
+    let inner = MARKER_OUTER + 0.5;
+    let tone = |hour: u32| if (6..18).contains(&hour) { day } else { night };
+
+    for hour in 0..24 {
+        let angle = td.world.hour_angle(hour as f64);
+        let color = tone(hour);
+        draw_arc(scene, inner, td.world.hour_angle(hour as f64 + 1.0), angle, color);
+        if hour % 2 == 0 {
+            let label = if hour == 0 { 24 } else { hour };
+            draw_ring_number(scene, label, angle, (inner + CHAPTER_OUTER) / 2.0, 3.5, color);
+        } else {
+            let (x1, y1) = hand_endpoint(angle, inner + 1.5);
+            let (x2, y2) = hand_endpoint(angle, CHAPTER_OUTER - 1.5);
+            scene.draw(&Line { x1, y1, x2, y2, color });
+        }
     }
 }
 
 // ══════════════════════════════════════════════════════════════
-// HOUR MARKERS — circle dots, rectangular batons, triangle
+// HOUR MARKERS — filled circle dots, rectangular batons, triangle
 // ══════════════════════════════════════════════════════════════
-fn paint_hour_markers(ctx: &mut Context, td: &ThemeData) {
+fn paint_hour_markers(scene: &mut Scene, td: &ThemeData) {
     let color = if td.lume_mode { Color::LightGreen } else { td.marker_color };
+    match td.hour_index {
+        HourIndex::DotsAndBatons => paint_dots_and_batons(scene, color),
+        HourIndex::Arabic(orientation) => {
+            paint_numerals(scene, &ARABIC, ARABIC_SIZE, orientation, 0.0, color);
+        }
+        HourIndex::Roman { orientation, iiii } => {
+            let mut numerals = ROMAN;
+            if !iiii {
+                numerals[4] = "IV";
+            }
+            paint_numerals(scene, &numerals, ROMAN_SIZE, orientation, 0.0, color);
+        }
+        HourIndex::Breguet(orientation) => {
+            paint_numerals(scene, &ARABIC, ARABIC_SIZE, orientation, BREGUET_SLANT, color);
+        }
+        HourIndex::Sticks => paint_sticks(scene, color),
+    }
+}
+
+/// Hour numerals, indexed by hour with 12 at 0.
+const ARABIC: [&str; 12] = ["12", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11"];
+const ROMAN: [&str; 12] = ["XII", "I", "II", "III", "IIII", "V", "VI", "VII", "VIII", "IX", "X", "XI"];
+const ARABIC_SIZE: TextSize = TextSize { width: 5.4, height: 9.0, gap: 1.8 };
+// Narrow glyphs, so VIII still fits between its neighbours
+const ROMAN_SIZE: TextSize = TextSize { width: 4.0, height: 9.0, gap: 0.6 };
+const BREGUET_SLANT: f64 = 0.25;
+
+/// Numerals at each hour, their outer edge on the marker ring.
+fn paint_numerals(
+    scene: &mut Scene, numerals: &[&str; 12], size: TextSize,
+    orientation: Orientation, slant: f64, color: Color,
+) {
+    for (h, numeral) in numerals.iter().enumerate() {
+        let angle = h as f64 * TAU / 12.0;
+        let half_w = size.measure(numeral) / 2.0;
+        let half_h = size.height / 2.0;
+        match orientation {
+            Orientation::Upright => {
+                // Pull the box in until its outermost corner touches the ring
+                let reach = angle.sin().abs() * half_w + angle.cos().abs() * half_h;
+                let (cx, cy) = hand_endpoint(angle, MARKER_OUTER - reach);
+                draw_text_rotated(scene, numeral, cx, cy, size, 0.0, slant, color);
+            }
+            Orientation::Radial => {
+                let (cx, cy) = hand_endpoint(angle, MARKER_OUTER - half_h);
+                draw_text_rotated(scene, numeral, cx, cy, size, angle, slant, color);
+            }
+        }
+    }
+}
+
+/// Thin sticks at every hour, doubled at 12.
+fn paint_sticks(scene: &mut Scene, color: Color) {
+    for h in 0..12 {
+        let angle = h as f64 * TAU / 12.0;
+        let offsets: &[f64] = if h == 0 { &[-0.02, 0.02] } else { &[0.0] };
+        for &offset in offsets {
+            let (x1, y1) = hand_endpoint(angle + offset, MARKER_INNER);
+            let (x2, y2) = hand_endpoint(angle + offset, MARKER_OUTER);
+            scene.draw(&Line { x1, y1, x2, y2, color });
+        }
+    }
+}
+
+/// Submariner indices: triangle at 12, batons at 3, 6 and 9, dots elsewhere.
+fn paint_dots_and_batons(scene: &mut Scene, color: Color) {
     for h in 1..=12 {
         let angle = (h as f64) * TAU / 12.0;
 
@@ -358,27 +900,29 @@ fn paint_hour_markers(ctx: &mut Context, td: &ThemeData) {
                 let (lx, ly) = hand_endpoint(angle - spread, MARKER_INNER);
                 let (rx, ry) = hand_endpoint(angle + spread, MARKER_INNER);
                 let (tx, ty) = hand_endpoint(angle, MARKER_OUTER);
-                ctx.draw(&Line { x1: lx, y1: ly, x2: tx, y2: ty, color });
-                ctx.draw(&Line { x1: rx, y1: ry, x2: tx, y2: ty, color });
-                ctx.draw(&Line { x1: lx, y1: ly, x2: rx, y2: ry, color });
+                scene.draw(&Polygon { points: &[(lx, ly), (tx, ty), (rx, ry)], color });
+                scene.draw(&Line { x1: lx, y1: ly, x2: tx, y2: ty, color });
+                scene.draw(&Line { x1: rx, y1: ry, x2: tx, y2: ty, color });
+                scene.draw(&Line { x1: lx, y1: ly, x2: rx, y2: ry, color });
             }
             3 | 6 | 9 => {
                 // Rectangular baton markers — drawn as rotated rectangles
-                draw_baton(ctx, angle, color);
+                draw_baton(scene, angle, color);
             }
             _ => {
                 // Circle/dot lume indices
                 let (mx, my) = hand_endpoint(angle, MARKER_CENTER);
-                ctx.draw(&Circle { x: mx, y: my, radius: 3.8, color });
+                scene.draw(&Disc { x: mx, y: my, radius: 3.8, color });
+                scene.draw(&Circle { x: mx, y: my, radius: 3.8, color });
             }
         }
     }
 }
 
-/// Draw a rotated rectangle. `w` = tangential width, `h` = radial length.
+/// Draw a filled rotated rectangle. `w` = tangential width, `h` = radial length.
 /// Centered at canvas position for `clock_angle` at `center_radius`.
 fn draw_rotated_rect(
-    ctx: &mut Context, clock_angle: f64, center_radius: f64,
+    scene: &mut Scene, clock_angle: f64, center_radius: f64,
     w: f64, h: f64, color: Color,
 ) {
     let (cx, cy) = hand_endpoint(clock_angle, center_radius);
@@ -392,9 +936,11 @@ fn draw_rotated_rect(
         let (rx, ry) = rotate_for_clock(x, y, clock_angle);
         (cx + rx, cy + ry)
     }).collect();
+    scene.draw(&Polygon { points: &pts, color });
+    // The outline keeps the edges crisp where the fill samples fall short
     for i in 0..4 {
         let j = (i + 1) % 4;
-        ctx.draw(&Line {
+        scene.draw(&Line {
             x1: pts[i].0, y1: pts[i].1,
             x2: pts[j].0, y2: pts[j].1,
             color,
@@ -403,24 +949,24 @@ fn draw_rotated_rect(
 }
 
 /// Draw a rectangular baton marker at the given clock angle.
-fn draw_baton(ctx: &mut Context, clock_angle: f64, color: Color) {
+fn draw_baton(scene: &mut Scene, clock_angle: f64, color: Color) {
     let w = 4.0;
     let h = (MARKER_OUTER - MARKER_INNER) + 2.0; // taller than default span
     let center_r = (MARKER_OUTER + MARKER_INNER) / 2.0;
-    draw_rotated_rect(ctx, clock_angle, center_r, w, h, color);
+    draw_rotated_rect(scene, clock_angle, center_r, w, h, color);
 }
 
 // ══════════════════════════════════════════════════════════════
 // CROWN — 5-pronged crown logo above RUSTLEX
 // ══════════════════════════════════════════════════════════════
-fn paint_crown(ctx: &mut Context, td: &ThemeData) {
+fn paint_crown(scene: &mut Scene, td: &ThemeData) {
     if td.lume_mode { return; }
     let scale = 0.7;
     let ox = 0.0;
     let oy = 54.0; // position above logo
 
     for &(x1, y1, x2, y2) in CROWN_SEGS {
-        ctx.draw(&Line {
+        scene.draw(&Line {
             x1: ox + x1 * scale, y1: oy + y1 * scale,
             x2: ox + x2 * scale, y2: oy + y2 * scale,
             color: td.logo_color,
@@ -429,7 +975,7 @@ fn paint_crown(ctx: &mut Context, td: &ThemeData) {
 
     // Dots at prong tips
     for &(dx, dy) in CROWN_DOTS {
-        ctx.draw(&Circle {
+        scene.draw(&Circle {
             x: ox + dx * scale, y: oy + dy * scale,
             radius: 0.6, color: td.logo_color,
         });
@@ -437,38 +983,33 @@ fn paint_crown(ctx: &mut Context, td: &ThemeData) {
 }
 
 // ══════════════════════════════════════════════════════════════
-// LOGO — "RUSTLEX" drawn with canvas lines
+// LOGO — the theme's logo text, drawn in the stroke font
 // ══════════════════════════════════════════════════════════════
-fn paint_logo(ctx: &mut Context, td: &ThemeData) {
+const LOGO_SIZE: TextSize = TextSize { width: 5.0, height: 8.0, gap: 2.5 };
+const LOGO_MAX_W: f64 = 56.0; // longer logos are scaled down to fit between the markers
+
+fn paint_logo(scene: &mut Scene, td: &ThemeData) {
     if td.lume_mode { return; }
-    let letter_w = 5.0;
-    let letter_h_scale = 0.8; // slightly squish height to fit
-    let gap = 2.5;
-    let total_w = 7.0 * letter_w + 6.0 * gap;
-    let start_x = -total_w / 2.0;
-    let start_y = 42.0;
-
-    for (i, segments) in LOGO_LETTERS.iter().enumerate() {
-        let ox = start_x + (i as f64) * (letter_w + gap);
-        let oy = start_y;
-        for &(lx1, ly1, lx2, ly2) in *segments {
-            // Scale x to letter_w (letters defined as 6-wide)
-            let sx = letter_w / 6.0;
-            // Scale y to letter_h (letters defined as 10-tall)
-            let sy = letter_h_scale;
-            ctx.draw(&Line {
-                x1: ox + lx1 * sx, y1: oy + ly1 * sy,
-                x2: ox + lx2 * sx, y2: oy + ly2 * sy,
-                color: td.logo_color,
-            });
-        }
-    }
+    let width = LOGO_SIZE.measure(&td.logo_text);
+    let size = if width > LOGO_MAX_W { LOGO_SIZE.scaled(LOGO_MAX_W / width) } else { LOGO_SIZE };
+    draw_text(scene, &td.logo_text, 0.0, 42.0, size, td.logo_color);
+}
+
+// ══════════════════════════════════════════════════════════════
+// LABEL — zone or city name, printed as text under the logo
+// ══════════════════════════════════════════════════════════════
+fn paint_label(scene: &mut Scene, td: &ThemeData) {
+    if td.lume_mode { return; }
+    let Some(label) = &td.label else { return };
+    scene.label(0.0, 30.0, label.clone(), td.logo_color);
 }
 
 // ══════════════════════════════════════════════════════════════
-// DATE WINDOW — at 3 o'clock with 7-segment digits
+// DATE WINDOW — at 3 o'clock, the day in the stroke font
 // ══════════════════════════════════════════════════════════════
-fn paint_date_window(ctx: &mut Context, td: &ThemeData, day: u32) {
+const DATE_SIZE: TextSize = TextSize { width: 6.0, height: 10.0, gap: 2.0 };
+
+fn paint_date_window(scene: &mut Scene, td: &ThemeData, day: u32) {
     if td.lume_mode || !td.has_date_window { return; }
 
     let cx = 50.0;
@@ -478,115 +1019,185 @@ fn paint_date_window(ctx: &mut Context, td: &ThemeData, day: u32) {
     let c = td.date_color;
 
     // Rectangle border
-    ctx.draw(&Line { x1: cx - hw, y1: cy - hh, x2: cx + hw, y2: cy - hh, color: c });
-    ctx.draw(&Line { x1: cx + hw, y1: cy - hh, x2: cx + hw, y2: cy + hh, color: c });
-    ctx.draw(&Line { x1: cx + hw, y1: cy + hh, x2: cx - hw, y2: cy + hh, color: c });
-    ctx.draw(&Line { x1: cx - hw, y1: cy + hh, x2: cx - hw, y2: cy - hh, color: c });
-
-    // Draw day as two 7-segment digits, centered
-    let tens = day / 10;
-    let ones = day % 10;
-    let dw = 6.0;
-    let dh = 10.0;
-    let dgap = 2.0;
-    let tw = dw * 2.0 + dgap;
-    let dx = cx - tw / 2.0;
-    let dy = cy - dh / 2.0;
-
-    if tens > 0 {
-        draw_digit(ctx, tens, dx, dy, dw, dh, c);
-    }
-    draw_digit(ctx, ones, dx + dw + dgap, dy, dw, dh, c);
-}
-
-/// Draw a 7-segment digit at position (ox, oy) — not rotated.
-fn draw_digit(ctx: &mut Context, digit: u32, ox: f64, oy: f64, w: f64, h: f64, color: Color) {
-    if digit > 9 { return; }
-    let segs = DIGITS[digit as usize];
-    let hh = h / 2.0;
-
-    let lines: [(f64, f64, f64, f64); 7] = [
-        (ox, oy + h, ox + w, oy + h),
-        (ox, oy + h, ox, oy + hh),
-        (ox + w, oy + h, ox + w, oy + hh),
-        (ox, oy + hh, ox + w, oy + hh),
-        (ox, oy + hh, ox, oy),
-        (ox + w, oy + hh, ox + w, oy),
-        (ox, oy, ox + w, oy),
-    ];
+    scene.draw(&Line { x1: cx - hw, y1: cy - hh, x2: cx + hw, y2: cy - hh, color: c });
+    scene.draw(&Line { x1: cx + hw, y1: cy - hh, x2: cx + hw, y2: cy + hh, color: c });
+    scene.draw(&Line { x1: cx + hw, y1: cy + hh, x2: cx - hw, y2: cy + hh, color: c });
+    scene.draw(&Line { x1: cx - hw, y1: cy + hh, x2: cx - hw, y2: cy - hh, color: c });
+
+    // Two digit slots, centred; a single-digit day leaves the tens slot blank
+    draw_text(scene, &format!("{:>2}", day), cx, cy - DATE_SIZE.height / 2.0, DATE_SIZE, c);
+}
+
+// ══════════════════════════════════════════════════════════════
+// SUB-DIALS — chronograph totalizers at 3 and 6, running seconds at 9
+// ══════════════════════════════════════════════════════════════
+const SUBDIAL_OFFSET: f64 = 34.0; // distance of each sub-dial center from the pivot
+const SUBDIAL_R: f64 = 15.0;
+
+fn paint_subdials(scene: &mut Scene, td: &ThemeData, clock: &ClockHands) {
+    if td.lume_mode { return; }
+    let minutes = td.chronograph / 60.0;
+    let hours = td.chronograph / 3600.0;
 
-    for (i, &(x1, y1, x2, y2)) in lines.iter().enumerate() {
-        if segs[i] {
-            ctx.draw(&Line { x1, y1, x2, y2, color });
+    // (center, graduations, hand angle)
+    let dials = [
+        ((SUBDIAL_OFFSET, 0.0), 6, (minutes % 30.0) / 30.0 * TAU),
+        ((0.0, -SUBDIAL_OFFSET), 12, (hours % 12.0) / 12.0 * TAU),
+        ((-SUBDIAL_OFFSET, 0.0), 12, clock.second_angle),
+    ];
+    for ((cx, cy), graduations, hand_angle) in dials {
+        scene.draw(&Circle { x: cx, y: cy, radius: SUBDIAL_R, color: td.marker_color });
+        for i in 0..graduations {
+            let angle = i as f64 * TAU / graduations as f64;
+            let (x1, y1) = hand_endpoint(angle, SUBDIAL_R - 3.0);
+            let (x2, y2) = hand_endpoint(angle, SUBDIAL_R);
+            scene.draw(&Line { x1: cx + x1, y1: cy + y1, x2: cx + x2, y2: cy + y2, color: td.bezel_color });
         }
+        let (hx, hy) = hand_endpoint(hand_angle, SUBDIAL_R - 2.0);
+        scene.draw(&Line { x1: cx, y1: cy, x2: cx + hx, y2: cy + hy, color: td.hour_hand_color });
+        scene.draw(&Circle { x: cx, y: cy, radius: 1.0, color: td.hour_hand_color });
     }
 }
 
 // ══════════════════════════════════════════════════════════════
-// HANDS — Mercedes hour, sword minute, lollipop second
+// HANDS — arrow GMT, Mercedes hour, sword minute, lollipop second
 // ══════════════════════════════════════════════════════════════
-fn paint_hands(ctx: &mut Context, td: &ThemeData, clock: &ClockHands) {
+fn paint_hands(scene: &mut Scene, td: &ThemeData, clock: &ClockHands) {
     let r = MARKER_INNER;
     let lume = td.lume_mode;
     let hc = if lume { Color::LightGreen } else { td.hour_hand_color };
     let mc = if lume { Color::LightGreen } else { td.minute_hand_color };
     let sc = td.second_hand_color; // second hand has no lume, stays red
+    let hands = td.hand_set;
+
+    // GMT hand reaches out to the 24-hour scale
+    if td.has_gmt_hand {
+        let gc = if lume { Color::LightGreen } else { td.gmt_hand_color };
+        draw_hand(scene, hands.gmt, clock.gmt_angle, MARKER_OUTER + 2.0, gc);
+    }
+    draw_hand(scene, hands.hour, clock.hour_angle, td.hour_hand_length * r, hc);
+    draw_hand(scene, hands.minute, clock.minute_angle, td.minute_hand_length * r, mc);
+    draw_hand(scene, hands.second, clock.second_angle, td.second_hand_length * r, sc);
+}
+
+/// Draw one hand pointing at `angle`, `len` units from the pivot to the tip.
+/// Shapes are laid out along the hand: y runs out from the pivot, x across it.
+fn draw_hand(scene: &mut Scene, style: HandStyle, angle: f64, len: f64, color: Color) {
+    let outline = |scene: &mut Scene, points: &[(f64, f64)]| draw_outline(scene, points, angle, color);
+    let solid = |scene: &mut Scene, points: &[(f64, f64)]| draw_solid(scene, points, angle, color);
+    let line = |scene: &mut Scene, from: f64, to: f64| {
+        let (x1, y1) = hand_endpoint(angle, from);
+        let (x2, y2) = hand_endpoint(angle, to);
+        scene.draw(&Line { x1, y1, x2, y2, color });
+    };
+    let ring = |scene: &mut Scene, at: f64, radius: f64| {
+        let (x, y) = hand_endpoint(angle, at);
+        scene.draw(&Circle { x, y, radius, color });
+    };
+
+    match style {
+        HandStyle::Mercedes => {
+            // Thick rectangular shaft + circle pip near the tip
+            let w = 3.5;
+            let shaft_len = len * 0.65;
+            draw_rotated_rect(scene, angle, shaft_len / 2.0, w, shaft_len, color);
+            let pip = len * 0.82;
+            ring(scene, pip, 2.5);
+            // Thin line connecting shaft to pip and pip to tip
+            line(scene, shaft_len, pip);
+            line(scene, pip, len);
+            // Short tail behind center
+            draw_rotated_rect(scene, angle + PI, 4.0, w, 8.0, color);
+        }
+        HandStyle::Sword => {
+            let w = 2.2;
+            draw_rotated_rect(scene, angle, len / 2.0, w, len, color);
+            // Pointed tip beyond the shaft
+            line(scene, len, len * 1.15);
+            draw_rotated_rect(scene, angle + PI, 4.0, w, 8.0, color);
+        }
+        HandStyle::Dauphine => {
+            solid(scene, &[(0.0, -6.0), (-2.8, 0.0), (0.0, len), (2.8, 0.0)]);
+        }
+        HandStyle::Leaf => {
+            solid(scene, &[
+                (0.0, 0.0), (-2.2, len * 0.25), (-2.6, len * 0.45), (-1.8, len * 0.75),
+                (0.0, len), (1.8, len * 0.75), (2.6, len * 0.45), (2.2, len * 0.25),
+            ]);
+            line(scene, -6.0, 0.0);
+        }
+        HandStyle::Cathedral => {
+            let (base, top) = (len * 0.45, len * 0.85);
+            draw_rotated_rect(scene, angle, base / 2.0, 2.0, base, color);
+            solid(scene, &[
+                (-1.0, base), (-3.2, base + (top - base) * 0.35), (-2.2, top - 2.0),
+                (0.0, top), (2.2, top - 2.0), (3.2, base + (top - base) * 0.35), (1.0, base),
+            ]);
+            line(scene, top, len);
+            draw_rotated_rect(scene, angle + PI, 4.0, 2.0, 8.0, color);
+        }
+        HandStyle::Pomme => {
+            let (apple, radius) = (len * 0.78, 2.4);
+            line(scene, -6.0, apple - radius);
+            ring(scene, apple, radius);
+            ring(scene, apple, radius * 0.4);
+            line(scene, apple + radius, len);
+        }
+        HandStyle::Syringe => {
+            let (barrel, needle) = (len * 0.3, len * 0.85);
+            line(scene, -6.0, barrel);
+            draw_rotated_rect(scene, angle, (barrel + needle) / 2.0, 3.2, needle - barrel, color);
+            line(scene, needle, len);
+        }
+        HandStyle::Pencil => {
+            let w = 1.4;
+            let shoulder = len - 5.0;
+            solid(scene, &[(-w, 0.0), (-w, shoulder), (0.0, len), (w, shoulder), (w, 0.0)]);
+            draw_rotated_rect(scene, angle + PI, 4.0, w * 2.0, 8.0, color);
+        }
+        HandStyle::Arrow => {
+            let (head_len, head_w) = (9.0, 3.5);
+            line(scene, 0.0, len - head_len);
+            solid(scene, &[(-head_w, len - head_len), (0.0, len), (head_w, len - head_len)]);
+        }
+        HandStyle::Skeleton => {
+            // Left as outlines: the open frame is what makes a skeleton hand
+            outline(scene, &[(-3.0, -5.0), (-3.0, len * 0.75), (0.0, len), (3.0, len * 0.75), (3.0, -5.0)]);
+            outline(scene, &[(-1.4, 2.0), (-1.4, len * 0.72), (0.0, len * 0.88), (1.4, len * 0.72), (1.4, 2.0)]);
+        }
+        HandStyle::Lollipop => {
+            // Thin line + circle "lollipop" near tip + counterbalance circle on tail
+            line(scene, 0.0, len);
+            ring(scene, len * 0.85, 1.8);
+            let tail = 0.20 * MARKER_INNER;
+            line(scene, 0.0, -tail);
+            ring(scene, -tail * 0.7, 1.2);
+        }
+        HandStyle::Needle => line(scene, -0.2 * MARKER_INNER, len),
+    }
+}
 
-    // ── Hour hand: Mercedes style ──
-    // Thick rectangular shaft + circle pip near the tip
-    let hour_len = td.hour_hand_length * r;
-    let hour_w = 3.5;  // wider than minute hand
-    let hour_shaft_len = hour_len * 0.65;
-    let hour_shaft_center = hour_shaft_len / 2.0;
-    // Main shaft (rectangle from center outward, stopping before the pip)
-    draw_rotated_rect(ctx, clock.hour_angle, hour_shaft_center, hour_w, hour_shaft_len, hc);
-    // Mercedes circle pip near the tip
-    let pip_r = hour_len * 0.82;
-    let (px, py) = hand_endpoint(clock.hour_angle, pip_r);
-    ctx.draw(&Circle { x: px, y: py, radius: 2.5, color: hc });
-    // Thin line connecting shaft to pip and pip to tip
-    let (shaft_end_x, shaft_end_y) = hand_endpoint(clock.hour_angle, hour_shaft_len);
-    ctx.draw(&Line { x1: shaft_end_x, y1: shaft_end_y, x2: px, y2: py, color: hc });
-    let (tip_x, tip_y) = hand_endpoint(clock.hour_angle, hour_len);
-    ctx.draw(&Line { x1: px, y1: py, x2: tip_x, y2: tip_y, color: hc });
-    // Short tail behind center
-    draw_rotated_rect(ctx, clock.hour_angle + PI, 4.0, hour_w, 8.0, hc);
-
-    // ── Minute hand: sword style ──
-    // Narrower rectangle, longer than hour hand
-    let min_len = td.minute_hand_length * r;
-    let min_w = 2.2;
-    let min_shaft_center = min_len / 2.0;
-    draw_rotated_rect(ctx, clock.minute_angle, min_shaft_center, min_w, min_len, mc);
-    // Pointed tip (tapers from shaft end to a point)
-    let min_tip_len = min_len * 0.15;
-    let (ms_x, ms_y) = hand_endpoint(clock.minute_angle, min_len);
-    let (mt_x, mt_y) = hand_endpoint(clock.minute_angle, min_len + min_tip_len);
-    ctx.draw(&Line { x1: ms_x, y1: ms_y, x2: mt_x, y2: mt_y, color: mc });
-    // Short tail behind center
-    draw_rotated_rect(ctx, clock.minute_angle + PI, 4.0, min_w, 8.0, mc);
-
-    // ── Second hand: lollipop style ──
-    // Thin line + circle "lollipop" near tip + counterbalance circle on tail
-    let sec_len = td.second_hand_length * r;
-    let (sx, sy) = hand_endpoint(clock.second_angle, sec_len);
-    ctx.draw(&Line { x1: 0.0, y1: 0.0, x2: sx, y2: sy, color: sc });
-    // Lollipop circle near the tip
-    let lollipop_r = sec_len * 0.85;
-    let (lx, ly) = hand_endpoint(clock.second_angle, lollipop_r);
-    ctx.draw(&Circle { x: lx, y: ly, radius: 1.8, color: sc });
-    // Counterweight tail with circle
-    let tail_len = 0.20 * r;
-    let (tx, ty) = hand_endpoint(clock.second_angle + PI, tail_len);
-    ctx.draw(&Line { x1: 0.0, y1: 0.0, x2: tx, y2: ty, color: sc });
-    let (cx, cy) = hand_endpoint(clock.second_angle + PI, tail_len * 0.7);
-    ctx.draw(&Circle { x: cx, y: cy, radius: 1.2, color: sc });
+/// Fill a polygon given in hand coordinates and trace its edge, like
+/// [`draw_rotated_rect`].
+fn draw_solid(scene: &mut Scene, points: &[(f64, f64)], angle: f64, color: Color) {
+    let canvas: Vec<(f64, f64)> = points.iter().map(|&(x, y)| rotate_for_clock(x, y, angle)).collect();
+    scene.draw(&Polygon { points: &canvas, color });
+    draw_outline(scene, points, angle, color);
+}
+
+/// Draw a closed polygon given in hand coordinates (see [`draw_hand`]).
+fn draw_outline(scene: &mut Scene, points: &[(f64, f64)], angle: f64, color: Color) {
+    let canvas: Vec<(f64, f64)> = points.iter().map(|&(x, y)| rotate_for_clock(x, y, angle)).collect();
+    for (i, &(x1, y1)) in canvas.iter().enumerate() {
+        let (x2, y2) = canvas[(i + 1) % canvas.len()];
+        scene.draw(&Line { x1, y1, x2, y2, color });
+    }
 }
 
 // ══════════════════════════════════════════════════════════════
 // CENTER DOT — pivot point
 // ══════════════════════════════════════════════════════════════
-fn paint_center_dot(ctx: &mut Context, td: &ThemeData) {
+fn paint_center_dot(scene: &mut Scene, td: &ThemeData) {
     let color = if td.lume_mode { Color::LightGreen } else { td.hour_hand_color };
-    ctx.draw(&Circle { x: 0.0, y: 0.0, radius: 2.5, color });
+    scene.draw(&Circle { x: 0.0, y: 0.0, radius: 2.5, color });
 }
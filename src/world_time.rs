@@ -0,0 +1,82 @@
+//! World time: a ring of cities around the dial and a 24-hour ring that turns
+//! once a day, so each city's local hour can be read off beside its name.
+//!
+//! The home zone (the one the hands show) sits at 12 o'clock. Every other
+//! city is placed by how far its current UTC offset, daylight saving
+//! included, is from home's: one hour of difference is 1/24 of a turn.
+
+use std::f64::consts::TAU;
+
+use chrono::{DateTime, Timelike, Utc};
+use chrono_tz::Tz;
+
+use crate::zone::Zone;
+
+/// A city on the city ring.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct City {
+    pub name: &'static str,
+    pub zone: Tz,
+}
+
+/// One city per standard UTC offset from −11 to +12, as on a classic world-time dial.
+pub const CITIES: &[City] = &[
+    City { name: "MIDWAY", zone: Tz::Pacific__Midway },
+    City { name: "HAWAII", zone: Tz::Pacific__Honolulu },
+    City { name: "ALASKA", zone: Tz::America__Anchorage },
+    City { name: "SEATTLE", zone: Tz::America__Los_Angeles },
+    City { name: "DENVER", zone: Tz::America__Denver },
+    City { name: "CHICAGO", zone: Tz::America__Chicago },
+    City { name: "NEW YORK", zone: Tz::America__New_York },
+    City { name: "HALIFAX", zone: Tz::America__Halifax },
+    City { name: "RIO", zone: Tz::America__Sao_Paulo },
+    City { name: "NORONHA", zone: Tz::America__Noronha },
+    City { name: "AZORES", zone: Tz::Atlantic__Azores },
+    City { name: "LONDON", zone: Tz::Europe__London },
+    City { name: "PARIS", zone: Tz::Europe__Paris },
+    City { name: "CAIRO", zone: Tz::Africa__Cairo },
+    City { name: "MOSCOW", zone: Tz::Europe__Moscow },
+    City { name: "DUBAI", zone: Tz::Asia__Dubai },
+    City { name: "KARACHI", zone: Tz::Asia__Karachi },
+    City { name: "DHAKA", zone: Tz::Asia__Dhaka },
+    City { name: "BANGKOK", zone: Tz::Asia__Bangkok },
+    City { name: "BEIJING", zone: Tz::Asia__Shanghai },
+    City { name: "TOKYO", zone: Tz::Asia__Tokyo },
+    City { name: "SYDNEY", zone: Tz::Australia__Sydney },
+    City { name: "NOUMEA", zone: Tz::Pacific__Noumea },
+    City { name: "FIJI", zone: Tz::Pacific__Fiji },
+];
+
+/// Where the rings stand at one instant.
+#[derive(Clone, Debug, PartialEq)]
+pub struct WorldTime {
+    /// Clock angle of 00 on the 24-hour ring. Hour `h` sits at
+    /// `ring_angle - h * TAU / 24`, so the ring counts up counter-clockwise.
+    pub ring_angle: f64,
+    /// Each city's name and clock angle, in the order given.
+    pub cities: Vec<(&'static str, f64)>,
+}
+
+impl WorldTime {
+    /// Ring positions at `instant` with `home` at 12 o'clock.
+    pub fn at(instant: DateTime<Utc>, home: Zone, cities: &[City]) -> Self {
+        let home_offset = home.offset_at(instant).local_minus_utc();
+        let local = instant + chrono::TimeDelta::seconds(home_offset as i64);
+        let hours = local.num_seconds_from_midnight() as f64 / 3600.0;
+
+        let cities = cities.iter()
+            .map(|city| {
+                let offset = Zone::Named(city.zone).offset_at(instant).local_minus_utc();
+                let hours_behind = (home_offset - offset) as f64 / 3600.0;
+                (city.name, (hours_behind * TAU / 24.0).rem_euclid(TAU))
+            })
+            .collect();
+
+        Self { ring_angle: hours * TAU / 24.0, cities }
+    }
+
+    /// Clock angle of an hour on the 24-hour ring.
+    pub fn hour_angle(&self, hour: f64) -> f64 {
+        self.ring_angle - hour * TAU / 24.0
+    }
+}
@@ -0,0 +1,143 @@
+use std::fmt;
+use std::str::FromStr;
+
+use chrono::{DateTime, FixedOffset, Local, NaiveDateTime, Offset, TimeZone, Utc};
+use chrono_tz::Tz;
+
+use crate::clock::{ClockHands, Movement};
+
+/// The time zone a watch face is set to.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Zone {
+    /// The system's local time zone.
+    #[default]
+    Local,
+    /// A fixed offset from UTC.
+    Fixed(FixedOffset),
+    /// An IANA time zone such as `Europe/London`, following its DST rules.
+    Named(Tz),
+}
+
+impl Zone {
+    /// Hand positions for the given instant, read in this zone.
+    pub fn hands(&self, instant: DateTime<Utc>, movement: impl Into<Movement>) -> ClockHands {
+        let movement = movement.into();
+        match self {
+            Zone::Local => ClockHands::at(&instant.with_timezone(&Local), movement),
+            Zone::Fixed(offset) => ClockHands::at(&instant.with_timezone(offset), movement),
+            Zone::Named(tz) => ClockHands::at(&instant.with_timezone(tz), movement),
+        }
+    }
+
+    /// Angle of the 24-hour GMT hand for the given instant, read in this zone.
+    pub fn gmt_angle(&self, instant: DateTime<Utc>) -> f64 {
+        match self {
+            Zone::Local => ClockHands::gmt_angle_at(&instant.with_timezone(&Local)),
+            Zone::Fixed(offset) => ClockHands::gmt_angle_at(&instant.with_timezone(offset)),
+            Zone::Named(tz) => ClockHands::gmt_angle_at(&instant.with_timezone(tz)),
+        }
+    }
+
+    /// The zone's offset from UTC at the given instant, including daylight saving.
+    pub fn offset_at(&self, instant: DateTime<Utc>) -> FixedOffset {
+        match self {
+            Zone::Local => instant.with_timezone(&Local).offset().fix(),
+            Zone::Fixed(offset) => *offset,
+            Zone::Named(tz) => instant.with_timezone(tz).offset().fix(),
+        }
+    }
+
+    /// The instant at which this zone's clocks read `wall`. A time repeated
+    /// when the clocks go back gives the earlier instant; one skipped when
+    /// they go forward gives `None`.
+    pub fn instant_at(&self, wall: NaiveDateTime) -> Option<DateTime<Utc>> {
+        match self {
+            Zone::Local => Local.from_local_datetime(&wall).earliest().map(|t| t.to_utc()),
+            Zone::Fixed(offset) => offset.from_local_datetime(&wall).earliest().map(|t| t.to_utc()),
+            Zone::Named(tz) => tz.from_local_datetime(&wall).earliest().map(|t| t.to_utc()),
+        }
+    }
+
+    /// A short name for the dial: the city of a named zone (`New York` for
+    /// `America/New_York`), otherwise the same as `Display`.
+    pub fn label(&self) -> String {
+        match self {
+            Zone::Named(tz) => {
+                let name = tz.name();
+                name.rsplit('/').next().unwrap_or(name).replace('_', " ")
+            }
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Zone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Zone::Local => write!(f, "local"),
+            Zone::Fixed(offset) if offset.local_minus_utc() == 0 => write!(f, "UTC"),
+            Zone::Fixed(offset) => write!(f, "UTC{}", offset),
+            Zone::Named(tz) => write!(f, "{}", tz.name()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseZoneError(String);
+
+impl fmt::Display for ParseZoneError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unrecognised time zone '{}' (expected 'local', 'UTC', an offset like '+05:30' or a name like 'Europe/London')", self.0)
+    }
+}
+
+impl std::error::Error for ParseZoneError {}
+
+impl FromStr for Zone {
+    type Err = ParseZoneError;
+
+    /// Accepts `local`, `UTC`/`Z`, offsets such as `+02`, `-0800`, `UTC+05:30`,
+    /// and IANA names such as `Asia/Tokyo` (case-insensitive).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ParseZoneError(s.to_string());
+        let trimmed = s.trim();
+        if trimmed.eq_ignore_ascii_case("local") {
+            return Ok(Zone::Local);
+        }
+        if trimmed.contains('/') {
+            return chrono_tz::TZ_VARIANTS.iter()
+                .find(|tz| tz.name().eq_ignore_ascii_case(trimmed))
+                .map(|&tz| Zone::Named(tz))
+                .ok_or_else(err);
+        }
+        let rest = trimmed
+            .strip_prefix("UTC").or_else(|| trimmed.strip_prefix("utc"))
+            .or_else(|| trimmed.strip_prefix("GMT")).or_else(|| trimmed.strip_prefix("gmt"))
+            .unwrap_or(trimmed);
+        if rest.is_empty() || rest.eq_ignore_ascii_case("z") {
+            return Ok(Zone::Fixed(FixedOffset::east_opt(0).unwrap()));
+        }
+
+        let (sign, digits) = match rest.as_bytes()[0] {
+            b'+' => (1, &rest[1..]),
+            b'-' => (-1, &rest[1..]),
+            _ => return Err(err()),
+        };
+        let digits: String = digits.chars().filter(|&c| c != ':').collect();
+        if digits.is_empty() || digits.len() > 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(err());
+        }
+        let (hours, minutes) = if digits.len() <= 2 {
+            (digits.parse::<i32>().unwrap(), 0)
+        } else {
+            let split = digits.len() - 2;
+            (digits[..split].parse::<i32>().unwrap(), digits[split..].parse::<i32>().unwrap())
+        };
+        if hours > 14 || minutes > 59 {
+            return Err(err());
+        }
+        FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+            .map(Zone::Fixed)
+            .ok_or_else(err)
+    }
+}
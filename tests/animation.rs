@@ -0,0 +1,56 @@
+use chrono::{TimeZone, Utc};
+use dialup::animation::Gif;
+use dialup::clock::ClockHands;
+use dialup::png;
+use dialup::theme::submariner::SubmarinerTheme;
+use dialup::WatchFace;
+
+fn frame(second: u32, width: u32) -> tiny_skia::Pixmap {
+    let time = Utc.with_ymd_and_hms(2024, 3, 9, 10, 8, second).unwrap();
+    let face = WatchFace::new(&SubmarinerTheme).hands(ClockHands::at(&time, false));
+    png::rasterise(&face.scene(&[]), width)
+}
+
+/// Each frame's delay, in hundredths of a second.
+fn delays(bytes: &[u8]) -> Vec<u16> {
+    let mut options = gif::DecodeOptions::new();
+    options.set_color_output(gif::ColorOutput::Indexed);
+    let mut decoder = options.read_info(bytes).unwrap();
+    let mut delays = Vec::new();
+    while let Some(frame) = decoder.read_next_frame().unwrap() {
+        assert_eq!((frame.width, frame.height), (64, 64));
+        delays.push(frame.delay);
+    }
+    delays
+}
+
+#[test]
+fn writes_a_looping_gif() {
+    let mut gif = Gif::new(Vec::new(), 64, 10).unwrap();
+    for second in 0..3 {
+        gif.frame(&frame(second, 64)).unwrap();
+    }
+    let bytes = gif.into_inner().unwrap();
+    assert!(bytes.starts_with(b"GIF89a"));
+    assert!(bytes.windows(11).any(|w| w == b"NETSCAPE2.0"), "no loop extension");
+    assert_eq!(delays(&bytes), [10, 10, 10]);
+}
+
+#[test]
+fn delays_keep_to_the_frame_rate() {
+    // 30 fps does not divide into hundredths: 3, 4, 3, 3, 4, 3, ...
+    let mut gif = Gif::new(Vec::new(), 64, 30).unwrap();
+    let pixmap = frame(0, 64);
+    for _ in 0..30 {
+        gif.frame(&pixmap).unwrap();
+    }
+    let delays = delays(&gif.into_inner().unwrap());
+    assert_eq!(delays.iter().map(|&d| d as u32).sum::<u32>(), 100);
+    assert!(delays.iter().all(|&d| d == 3 || d == 4));
+}
+
+#[test]
+fn frames_must_match_the_size() {
+    let mut gif = Gif::new(Vec::new(), 64, 10).unwrap();
+    assert!(gif.frame(&frame(0, 32)).is_err());
+}
@@ -0,0 +1,47 @@
+use std::io::Write;
+use std::time::Duration;
+
+use dialup::asciicast::{Cast, Recorder};
+
+fn lines(bytes: Vec<u8>) -> Vec<String> {
+    String::from_utf8(bytes).unwrap().lines().map(String::from).collect()
+}
+
+#[test]
+fn header_names_the_terminal() {
+    let cast = Cast::new(Vec::new(), 80, 24, 1_700_000_000, Some("xterm-256color")).unwrap();
+    assert_eq!(
+        lines(cast.into_inner().unwrap()),
+        [r#"{"version": 2, "width": 80, "height": 24, "timestamp": 1700000000, "env": {"TERM": "xterm-256color"}}"#]
+    );
+    let cast = Cast::new(Vec::new(), 80, 24, 0, None).unwrap();
+    assert_eq!(lines(cast.into_inner().unwrap()), [r#"{"version": 2, "width": 80, "height": 24, "timestamp": 0}"#]);
+}
+
+#[test]
+fn events_are_timed_and_escaped() {
+    let mut cast = Cast::new(Vec::new(), 80, 24, 0, None).unwrap();
+    cast.output(Duration::from_millis(1500), "\x1b[1;1H\"⣿\"\\\r\n".as_bytes()).unwrap();
+    cast.resize(Duration::from_micros(2_000_001), 100, 30).unwrap();
+    let lines = lines(cast.into_inner().unwrap());
+    assert_eq!(lines[1], r#"[1.500000, "o", "\u001b[1;1H\"⣿\"\\\r\n"]"#);
+    assert_eq!(lines[2], r#"[2.000001, "r", "100x30"]"#);
+}
+
+#[test]
+fn recorder_passes_output_through_and_records_each_flush() {
+    let cast = Cast::new(Vec::new(), 80, 24, 0, None).unwrap();
+    let mut recorder = Recorder::new(Vec::new(), cast);
+    recorder.write_all(b"ab").unwrap();
+    recorder.write_all(b"c").unwrap();
+    recorder.flush().unwrap();
+    recorder.flush().unwrap(); // nothing new: no event
+    recorder.write_all(b"d").unwrap();
+    recorder.flush().unwrap();
+    let (out, cast) = recorder.into_parts();
+    assert_eq!(out, b"abcd");
+    let lines = lines(cast.into_inner().unwrap());
+    assert_eq!(lines.len(), 3);
+    assert!(lines[1].ends_with(r#", "o", "abc"]"#), "{}", lines[1]);
+    assert!(lines[2].ends_with(r#", "o", "d"]"#), "{}", lines[2]);
+}
@@ -0,0 +1,96 @@
+mod common;
+
+use common::{env, face};
+use dialup::capabilities::{Capabilities, ColorDepth};
+use dialup::marker::{self, Marker};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use ratatui::widgets::Widget;
+
+fn render(marker: Marker) -> Buffer {
+    let area = Rect::new(0, 0, 60, 30);
+    let mut buf = Buffer::empty(area);
+    face().marker(marker).render(area, &mut buf);
+    buf
+}
+
+#[test]
+fn detects_colour_depth() {
+    let depth = |vars: &[(&str, &str)]| Capabilities::detect(env(vars)).color;
+    assert_eq!(depth(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")]), ColorDepth::TrueColor);
+    assert_eq!(depth(&[("TERM", "xterm-256color")]), ColorDepth::Indexed256);
+    assert_eq!(depth(&[("TERM", "xterm")]), ColorDepth::Ansi16);
+    assert_eq!(depth(&[("TERM", "linux")]), ColorDepth::Ansi16);
+    assert_eq!(depth(&[("TERM", "dumb")]), ColorDepth::Mono);
+    assert_eq!(depth(&[]), ColorDepth::TrueColor);
+    // NO_COLOR wins over everything, but only when it has a value
+    assert_eq!(depth(&[("TERM", "xterm-256color"), ("COLORTERM", "truecolor"), ("NO_COLOR", "1")]), ColorDepth::Mono);
+    assert_eq!(depth(&[("TERM", "xterm-256color"), ("NO_COLOR", "")]), ColorDepth::Indexed256);
+}
+
+#[test]
+fn picks_a_marker_the_font_can_show() {
+    let marker = |vars: &[(&str, &str)]| Capabilities::detect(env(vars)).marker();
+    assert_eq!(marker(&[("TERM", "xterm-256color"), ("LANG", "en_GB.UTF-8")]), None);
+    assert_eq!(marker(&[("TERM", "linux"), ("LANG", "en_GB.UTF-8")]), Some(Marker::HalfBlock));
+    assert_eq!(marker(&[("TERM", "xterm"), ("LANG", "C")]), Some(Marker::Block));
+    // LC_ALL overrides LANG
+    assert_eq!(marker(&[("TERM", "xterm"), ("LANG", "C"), ("LC_ALL", "de_DE.utf8")]), None);
+}
+
+#[test]
+fn reduces_colours_to_the_depth() {
+    let orange = Color::Rgb(0xff, 0x87, 0x00);
+    assert_eq!(ColorDepth::TrueColor.reduce(orange), orange);
+    assert_eq!(ColorDepth::Indexed256.reduce(orange), Color::Indexed(208));
+    assert_eq!(ColorDepth::Indexed256.reduce(Color::Rgb(0x80, 0x80, 0x80)), Color::Indexed(244));
+    assert_eq!(ColorDepth::Ansi16.reduce(Color::Rgb(0, 0xd0, 0)), Color::Green);
+    assert_eq!(ColorDepth::Ansi16.reduce(Color::Indexed(9)), Color::LightRed);
+    assert_eq!(ColorDepth::Ansi16.reduce(Color::Blue), Color::Blue);
+    assert_eq!(ColorDepth::Mono.reduce(Color::Yellow), Color::Reset);
+}
+
+#[test]
+fn mono_keeps_both_halves_of_a_half_block() {
+    let area = Rect::new(0, 0, 2, 1);
+    let mut buf = Buffer::empty(area);
+    buf[(0, 0)].set_char('▀').set_fg(Color::Green).set_bg(Color::Blue);
+    buf[(1, 0)].set_char('▀').set_fg(Color::Green);
+    ColorDepth::Mono.reduce_buffer(&mut buf, area);
+    assert_eq!(buf[(0, 0)].symbol(), "█");
+    assert_eq!(buf[(1, 0)].symbol(), "▀");
+    assert!(buf.content.iter().all(|cell| cell.fg == Color::Reset && cell.bg == Color::Reset));
+}
+
+#[test]
+fn quadrants_fold_braille_dots() {
+    let area = Rect::new(0, 0, 3, 1);
+    let mut buf = Buffer::empty(area);
+    buf[(0, 0)].set_char('⣿');
+    buf[(1, 0)].set_char('⠁'); // the top-left dot only
+    buf[(2, 0)].set_char('x');
+    marker::fold_quadrants(&mut buf, area);
+    assert_eq!([buf[(0, 0)].symbol(), buf[(1, 0)].symbol(), buf[(2, 0)].symbol()], ["█", "▘", "x"]);
+
+    let buf = render(Marker::Quadrant);
+    assert!(buf.content.iter().all(|cell| !('\u{2801}'..='\u{28ff}').contains(&cell.symbol().chars().next().unwrap())));
+    assert!(buf.content.iter().any(|cell| cell.symbol() == "▘" || cell.symbol() == "▗"));
+}
+
+#[test]
+fn the_override_cycles_back_to_automatic() {
+    let mut marker = None;
+    let mut seen = Vec::new();
+    for _ in 0..6 {
+        marker = Marker::next(marker);
+        seen.push(marker);
+    }
+    assert_eq!(seen, [
+        Some(Marker::Braille), Some(Marker::HalfBlock), Some(Marker::Block),
+        Some(Marker::Dot), Some(Marker::Quadrant), None,
+    ]);
+    for marker in Marker::CYCLE {
+        assert_eq!(marker.to_string().parse(), Ok(marker));
+    }
+}
@@ -0,0 +1,62 @@
+//! The `dialup` binary's option and config handling, run through
+//! `dialup export` so nothing needs a terminal.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process::{Command, Output};
+
+/// An empty home for the binary to run in, so no real config is read.
+fn home(test: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("dialup-cli-{}-{}", std::process::id(), test));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("config/dialup")).unwrap();
+    dir
+}
+
+/// Export an SVG to standard output with the given extra arguments.
+fn export(home: &PathBuf, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_dialup"))
+        .args(["export", "--svg", "-", "--time", "2024-03-09T10:08:37Z"])
+        .args(args)
+        .env_clear()
+        .env("HOME", home)
+        .env("XDG_CONFIG_HOME", home.join("config"))
+        .env("XDG_STATE_HOME", home.join("state"))
+        .env("XDG_DATA_HOME", home.join("data"))
+        .output()
+        .unwrap()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn dive_takes_minutes_up_to_an_hour() {
+    let home = home("dive");
+    for ok in ["1", "45", "59.9"] {
+        let output = export(&home, &["--dive", ok]);
+        assert!(output.status.success(), "--dive {}: {}", ok, stderr(&output));
+    }
+    for bad in ["0.5", "60", "75"] {
+        let output = export(&home, &["--dive", bad]);
+        assert_eq!(output.status.code(), Some(2), "--dive {}", bad);
+        assert!(stderr(&output).contains("expected minutes from 1 up to 60"), "{}", stderr(&output));
+    }
+}
+
+#[test]
+fn config_errors_name_the_line() {
+    let home = home("config-line");
+    let config = home.join("config/dialup/config.toml");
+    // The bad zone starts its line, as in a multi-line array
+    fs::write(&config, "stars = true\nzones = [\n\"UTC\",\n\"Mars/Olympus\",\n]\n").unwrap();
+    let output = export(&home, &[]);
+    assert_eq!(output.status.code(), Some(2));
+    let expected = format!("{}:4: invalid value 'Mars/Olympus' for 'zones'", config.display());
+    assert!(stderr(&output).contains(&expected), "{}", stderr(&output));
+
+    fs::write(&config, "theme = \"nautilus\"\ndive = 75\n").unwrap();
+    let output = export(&home, &[]);
+    assert!(stderr(&output).contains(":2: invalid value '75' for 'dive'"), "{}", stderr(&output));
+}
@@ -0,0 +1,62 @@
+use std::f64::consts::TAU;
+
+use chrono::{NaiveDate, TimeZone, Utc};
+use dialup::clock::{ClockHands, Movement};
+
+/// 10:08:37 and `millis` on the 9th, as (seconds, minutes, hours) read off the hands.
+fn read(millis: u32, movement: Movement) -> (f64, f64, f64) {
+    let time = NaiveDate::from_ymd_opt(2024, 3, 9).unwrap().and_hms_milli_opt(10, 8, 37, millis).unwrap();
+    let hands = ClockHands::at(&Utc.from_utc_datetime(&time), movement);
+    (hands.second_angle / TAU * 60.0, hands.minute_angle / TAU * 60.0, hands.hour_angle / TAU * 12.0)
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+#[test]
+fn every_hand_follows_the_same_beat() {
+    // Quartz: all three hands hold until the next whole second
+    let (s, m, h) = read(700, Movement::Quartz);
+    assert!(close(s, 37.0));
+    assert!(close(m, 8.0 + 37.0 / 60.0));
+    assert!(close(h, 10.0 + (8.0 * 60.0 + 37.0) / 3600.0));
+
+    // 28,800 vph: eight steps a second, 0.7 s shows as 0.625 s
+    let (s, m, _) = read(700, Movement::Mechanical(28_800));
+    assert!(close(s, 37.625));
+    assert!(close(m, 8.0 + 37.625 / 60.0));
+    // 36,000 vph: ten steps a second
+    assert!(close(read(750, Movement::Mechanical(36_000)).0, 37.7));
+
+    // Spring Drive glides, minute hand included
+    let (s, m, _) = read(700, Movement::SpringDrive);
+    assert!(close(s, 37.7));
+    assert!(close(m, 8.0 + 37.7 / 60.0));
+}
+
+#[test]
+fn complications_jump_one_hand() {
+    // Deadbeat seconds jump, while the train behind them runs at 28,800 vph
+    let (s, m, _) = read(700, Movement::Deadbeat);
+    assert!(close(s, 37.0));
+    assert!(close(m, 8.0 + 37.625 / 60.0));
+
+    // A jumping hour sits on the hour until the next one
+    let (s, _, h) = read(700, Movement::JumpingHour);
+    assert!(close(s, 37.625));
+    assert!(close(h, 10.0));
+}
+
+#[test]
+fn movements_round_trip_through_their_names() {
+    for movement in Movement::PRESETS {
+        assert_eq!(movement.to_string().parse(), Ok(movement));
+    }
+    assert_eq!("Mechanical-19,800".parse(), Ok(Movement::Mechanical(19_800)));
+    assert!("mechanical-100".parse::<Movement>().is_err());
+    assert!("automatic".parse::<Movement>().is_err());
+    // The old switch still works
+    assert_eq!(Movement::from(true), Movement::SpringDrive);
+    assert_eq!(Movement::JumpingHour.next(), Movement::Quartz);
+}
@@ -0,0 +1,22 @@
+//! Fixtures shared by the integration tests. Each test crate uses only some
+//! of them.
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use chrono::{TimeZone, Utc};
+use dialup::clock::ClockHands;
+use dialup::theme::submariner::SubmarinerTheme;
+use dialup::WatchFace;
+
+/// The Submariner face at 10:08:37 on the 9th.
+pub fn face() -> WatchFace<'static> {
+    let time = Utc.with_ymd_and_hms(2024, 3, 9, 10, 8, 37).unwrap();
+    WatchFace::new(&SubmarinerTheme).hands(ClockHands::at(&time, false))
+}
+
+/// An environment lookup that sees only `vars`.
+pub fn env(vars: &[(&str, &str)]) -> impl Fn(&str) -> Option<String> {
+    let vars: HashMap<String, String> = vars.iter().map(|(k, v)| (k.to_string(), v.to_string())).collect();
+    move |name| vars.get(name).cloned()
+}
@@ -0,0 +1,85 @@
+use std::f64::consts::TAU;
+
+use dialup::dive::{self, DiveAlarm};
+use dialup::theme::{BezelAction, BezelSpec};
+
+/// Clock angle of the minute hand `minutes` past the hour.
+fn minutes(m: f64) -> f64 {
+    m / 60.0 * TAU
+}
+
+/// Where a bezel at `offset` settles once set to the hand, and the timer then.
+fn set_to_hand(spec: BezelSpec, offset: f64, hand: f64) -> (f64, f64) {
+    let offset = (offset + spec.turn_to_hand(offset, hand)).rem_euclid(TAU);
+    (offset, dive::elapsed(hand, offset))
+}
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-6
+}
+
+#[test]
+fn a_ratcheting_bezel_stops_on_the_click_before_the_hand() {
+    let dive = BezelSpec { clicks: 120, action: BezelAction::Unidirectional };
+    let (offset, elapsed) = set_to_hand(dive, 0.0, minutes(10.3));
+    assert!(close(offset, minutes(10.0)), "{}", offset);
+    assert!(close(elapsed, 0.3), "{}", elapsed);
+
+    // A friction-lock bezel stops right on the hand
+    let friction = BezelSpec { clicks: 120, action: BezelAction::FrictionLock };
+    let (offset, elapsed) = set_to_hand(friction, 0.0, minutes(10.3));
+    assert!(close(offset, minutes(10.3)) && close(elapsed, 0.0));
+}
+
+#[test]
+fn a_unidirectional_bezel_only_turns_counter_clockwise() {
+    let dive = BezelSpec { clicks: 120, action: BezelAction::Unidirectional };
+    // Behind the hand, it goes the long way round rather than back
+    assert!(close(dive.turn_to_hand(minutes(5.0), minutes(10.0)), minutes(-55.0)));
+    assert!(close(dive.turn_to_hand(minutes(20.0), minutes(10.0)), minutes(-10.0)));
+    assert_eq!(dive.turn_to_hand(minutes(10.0), minutes(10.0)), 0.0);
+
+    // The others take the short way, and a fixed bezel stays put
+    let gmt = BezelSpec { clicks: 24, action: BezelAction::Bidirectional };
+    assert!(close(gmt.turn_to_hand(minutes(5.0), minutes(10.0)), minutes(5.0)));
+    assert!(close(gmt.turn_to_hand(minutes(0.0), minutes(50.0)), minutes(-10.0)));
+    let fixed = BezelSpec { clicks: 120, action: BezelAction::Fixed };
+    assert_eq!(fixed.turn_to_hand(0.0, minutes(10.0)), 0.0);
+}
+
+#[test]
+fn a_pip_on_the_hand_reads_zero_not_sixty() {
+    let dive = BezelSpec { clicks: 120, action: BezelAction::Unidirectional };
+    for click in 0..120 {
+        let hand = click as f64 * dive.click_angle();
+        let (_, elapsed) = set_to_hand(dive, minutes(7.0), hand);
+        assert!(elapsed < 1e-6, "click {} reads {}", click, elapsed);
+    }
+    assert!(close(dive::elapsed(minutes(15.0), 0.0), 15.0));
+    assert!(close(dive::elapsed(minutes(5.0), minutes(50.0)), 15.0));
+}
+
+#[test]
+fn the_alarm_sounds_once_as_time_passes_the_duration() {
+    let mut alarm = DiveAlarm::new(Some(10.0));
+    assert!(!alarm.check(9.5));
+    assert!(alarm.check(10.0));
+    assert!(!alarm.check(10.5));
+    assert!(!alarm.check(30.0));
+
+    // Turning the bezel past the duration is not time passing
+    let mut alarm = DiveAlarm::new(Some(10.0));
+    assert!(!alarm.check(2.0));
+    alarm.restart(12.0);
+    assert!(!alarm.check(12.5));
+
+    // Wrapping round from 60 to 0 is not passing it either; the next pass is
+    let mut alarm = DiveAlarm::new(Some(10.0));
+    alarm.restart(59.5);
+    assert!(!alarm.check(0.5));
+    assert!(!alarm.check(9.9));
+    assert!(alarm.check(10.1));
+
+    let mut silent = DiveAlarm::new(None);
+    assert!(!silent.check(0.0) && !silent.check(59.0));
+}
@@ -0,0 +1,81 @@
+use std::f64::consts::TAU;
+
+use dialup::fill::{AnnulusSector, Disc, Polygon};
+use ratatui::buffer::Buffer;
+use ratatui::layout::Rect;
+use ratatui::style::Color;
+use ratatui::symbols::Marker;
+use ratatui::widgets::canvas::{Canvas, Circle, Context};
+use ratatui::widgets::Widget;
+
+/// Paint on a 20 × 10 cell canvas spanning ±10 and return it as text.
+fn paint(marker: Marker, f: impl Fn(&mut Context)) -> Vec<String> {
+    let area = Rect::new(0, 0, 20, 10);
+    let mut buf = Buffer::empty(area);
+    Canvas::default()
+        .x_bounds([-10.0, 10.0])
+        .y_bounds([-10.0, 10.0])
+        .marker(marker)
+        .paint(f)
+        .render(area, &mut buf);
+    (0..area.height)
+        .map(|y| (0..area.width).map(|x| buf[(x, y)].symbol()).collect())
+        .collect()
+}
+
+fn cell(lines: &[String], x: usize, y: usize) -> char {
+    lines[y].chars().nth(x).unwrap()
+}
+
+#[test]
+fn disc_fills_every_braille_dot_inside() {
+    let circle = paint(Marker::Braille, |ctx| {
+        ctx.draw(&Circle { x: 0.0, y: 0.0, radius: 8.0, color: Color::White });
+    });
+    let disc = paint(Marker::Braille, |ctx| {
+        ctx.draw(&Disc { x: 0.0, y: 0.0, radius: 8.0, color: Color::White });
+    });
+
+    // The centre cell is empty for an outline and solid for a disc
+    assert_eq!(cell(&circle, 10, 5), ' ');
+    assert_eq!(cell(&disc, 10, 5), '⣿');
+    // Nothing spills past the radius
+    assert_eq!(cell(&disc, 0, 0), ' ');
+    assert_eq!(cell(&disc, 19, 9), ' ');
+}
+
+#[test]
+fn polygon_fills_at_half_block_resolution() {
+    let square = [(-5.0, -5.0), (5.0, -5.0), (5.0, 5.0), (-5.0, 5.0)];
+    let lines = paint(Marker::HalfBlock, |ctx| {
+        ctx.draw(&Polygon { points: &square, color: Color::White });
+    });
+
+    assert_eq!(cell(&lines, 10, 5), '█');
+    assert_eq!(cell(&lines, 2, 5), ' ');
+}
+
+#[test]
+fn annulus_sector_leaves_the_hole_and_the_rest_of_the_ring_empty() {
+    // The right half of a ring: 12 o'clock round to 6 o'clock
+    let lines = paint(Marker::Braille, |ctx| {
+        ctx.draw(&AnnulusSector { inner: 4.0, outer: 9.0, from: 0.0, to: TAU / 2.0, color: Color::White });
+    });
+
+    assert_eq!(cell(&lines, 10, 5), ' ', "hole");
+    assert_eq!(cell(&lines, 16, 5), '⣿', "3 o'clock");
+    assert_eq!(cell(&lines, 3, 5), ' ', "9 o'clock");
+}
+
+#[test]
+fn shapes_over_the_edge_fill_the_part_on_the_canvas() {
+    // Centred on the right edge, and on a corner with its box past two sides
+    let lines = paint(Marker::Braille, |ctx| {
+        ctx.draw(&Disc { x: 10.0, y: 0.0, radius: 4.0, color: Color::White });
+        ctx.draw(&Disc { x: -10.0, y: 10.0, radius: 6.0, color: Color::White });
+    });
+
+    assert_ne!(cell(&lines, 19, 5), ' ', "right edge");
+    assert_eq!(cell(&lines, 0, 0), '⣿', "top-left corner");
+    assert_eq!(cell(&lines, 10, 5), ' ');
+}
@@ -0,0 +1,22 @@
+use dialup::font::{glyph, TextSize};
+
+#[test]
+fn every_character_has_its_own_glyph() {
+    let chars: Vec<char> = ('A'..='Z').chain('0'..='9').collect();
+    for (i, &a) in chars.iter().enumerate() {
+        for &b in &chars[i + 1..] {
+            assert_ne!(glyph(a), glyph(b), "{} and {} are drawn alike", a, b);
+        }
+    }
+    assert_eq!(glyph('q'), glyph('Q'));
+    assert_eq!(glyph('Ü'), None);
+}
+
+#[test]
+fn text_is_measured_by_its_drawable_characters() {
+    let size = TextSize::of_height(10.0);
+    assert_eq!(size.measure(""), 0.0);
+    assert_eq!(size.measure("I"), 6.0);
+    assert_eq!(size.measure("50"), 2.0 * size.advance() - size.gap);
+    assert_eq!(size.measure("5Ü0"), size.measure("50"));
+}
@@ -0,0 +1,68 @@
+mod common;
+
+use common::env;
+use dialup::graphics::{self, Mode, Protocol};
+use tiny_skia::{ColorU8, Pixmap};
+
+#[test]
+fn kitty_sends_a_png_in_chunks() {
+    assert_eq!(graphics::kitty(b"hi"), "\x1b_Ga=T,f=100,q=2,C=1,z=-1,m=0;aGk=\x1b\\");
+
+    // 6,200 bytes are 8,268 base64 characters: three chunks
+    let out = graphics::kitty(&[0u8; 6200]);
+    let chunks: Vec<&str> = out.split("\x1b\\").filter(|s| !s.is_empty()).collect();
+    assert_eq!(chunks.len(), 3);
+    assert!(chunks[0].starts_with("\x1b_Ga=T,f=100,q=2,C=1,z=-1,m=1;"));
+    assert!(chunks[1].starts_with("\x1b_Gm=1;"));
+    assert!(chunks[2].starts_with("\x1b_Gm=0;"));
+    let payload: usize = chunks.iter().map(|c| c.len() - c.find(';').unwrap() - 1).sum();
+    assert_eq!(payload, 8268);
+}
+
+#[test]
+fn iterm2_names_the_size_in_pixels() {
+    assert_eq!(
+        graphics::iterm2(b"abc", 300),
+        "\x1b]1337;File=inline=1;size=3;width=300px;height=300px;preserveAspectRatio=1;doNotMoveCursor=1:YWJj\x07"
+    );
+}
+
+#[test]
+fn sixel_paints_each_band_once_per_colour() {
+    // A red dot in the top-left corner of a black 2 × 7 image
+    let mut pixmap = Pixmap::new(2, 7).unwrap();
+    pixmap.fill(tiny_skia::Color::BLACK);
+    pixmap.pixels_mut()[0] = ColorU8::from_rgba(255, 0, 0, 255).premultiply();
+
+    assert_eq!(
+        graphics::sixel(&pixmap),
+        "\x1bP0;0;0q\"1;1;2;7#0;2;0;0;0#180;2;100;0;0#0}~$#180@?-#0@@-\x1b\\"
+    );
+}
+
+#[test]
+fn sixel_compresses_runs() {
+    let mut pixmap = Pixmap::new(10, 6).unwrap();
+    pixmap.fill(tiny_skia::Color::BLACK);
+    assert_eq!(graphics::sixel(&pixmap), "\x1bP0;0;0q\"1;1;10;6#0;2;0;0;0#0!10~-\x1b\\");
+}
+
+#[test]
+fn detects_the_protocol_from_the_environment() {
+    assert_eq!(Protocol::detect(env(&[("TERM", "xterm-kitty")])), Some(Protocol::Kitty));
+    assert_eq!(Protocol::detect(env(&[("TERM", "xterm-256color"), ("TERM_PROGRAM", "iTerm.app")])), Some(Protocol::Iterm2));
+    assert_eq!(Protocol::detect(env(&[("TERM", "foot")])), Some(Protocol::Sixel));
+    assert_eq!(Protocol::detect(env(&[("TERM", "xterm-256color")])), None);
+    // Not through a multiplexer, even in a terminal that has one
+    assert_eq!(Protocol::detect(env(&[("TERM", "tmux-256color"), ("KITTY_WINDOW_ID", "1")])), None);
+}
+
+#[test]
+fn modes_fall_back_to_the_canvas() {
+    let kitty = env(&[("TERM", "xterm-kitty")]);
+    assert_eq!("auto".parse::<Mode>().unwrap().protocol(&kitty), Some(Protocol::Kitty));
+    assert_eq!("off".parse::<Mode>().unwrap().protocol(&kitty), None);
+    assert_eq!("Sixel".parse::<Mode>().unwrap().protocol(env(&[])), Some(Protocol::Sixel));
+    assert_eq!("auto".parse::<Mode>().unwrap().protocol(env(&[])), None);
+    assert!("png".parse::<Mode>().is_err());
+}
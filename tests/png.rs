@@ -0,0 +1,57 @@
+mod common;
+
+use common::face;
+use dialup::png;
+use dialup::stars::{generate_stars, DEFAULT_SEED};
+use tiny_skia::Pixmap;
+
+/// The colour at a scene point, on a 400 px image.
+fn pixel(pixmap: &Pixmap, x: f64, y: f64) -> (u8, u8, u8) {
+    let px = ((x + 100.0) * 2.0) as u32;
+    let py = ((100.0 - y) * 2.0) as u32;
+    let c = pixmap.pixel(px, py).unwrap();
+    (c.red(), c.green(), c.blue())
+}
+
+/// How many pixels in the top-left corner, well outside the case, are lit.
+fn lit_corner(pixmap: &Pixmap) -> usize {
+    (0..50).flat_map(|y| (0..50).map(move |x| (x, y)))
+        .filter(|&(x, y)| pixmap.pixel(x, y).unwrap().red() > 0)
+        .count()
+}
+
+#[test]
+fn draws_at_any_size() {
+    for width in [64, 400, 1000] {
+        let pixmap = png::rasterise(&face().scene(&[]), width);
+        assert_eq!((pixmap.width(), pixmap.height()), (width, width));
+    }
+    let bytes = png::render(&face().scene(&[]), 128).unwrap();
+    assert!(bytes.starts_with(b"\x89PNG\r\n\x1a\n"));
+}
+
+#[test]
+fn fills_take_the_theme_colours() {
+    let pixmap = png::rasterise(&face().scene(&[]), 400);
+    // Inside the 12 o'clock triangle and the 3 o'clock baton, in marker green
+    assert_eq!(pixel(&pixmap, 0.0, 68.0), (0x00, 0xcd, 0x00));
+    assert_eq!(pixel(&pixmap, 69.5, 0.0), (0x00, 0xcd, 0x00));
+    // The open dial between them is left black
+    assert_eq!(pixel(&pixmap, 40.0, 20.0), (0, 0, 0));
+}
+
+#[test]
+fn lume_turns_the_bezel_off_and_the_plots_light_green() {
+    let pixmap = png::rasterise(&face().lume(true).scene(&[]), 400);
+    assert_eq!(pixel(&pixmap, 0.0, 68.0), (0x00, 0xff, 0x00));
+    assert_eq!(pixel(&pixmap, -97.0, 0.0), (0, 0, 0), "bezel edge");
+}
+
+#[test]
+fn stars_are_drawn_outside_the_case() {
+    let stars = generate_stars(DEFAULT_SEED);
+    let with = png::rasterise(&face().stars(true).scene(&stars), 400);
+    let without = png::rasterise(&face().scene(&stars), 400);
+    assert!(lit_corner(&with) > 0);
+    assert_eq!(lit_corner(&without), 0);
+}
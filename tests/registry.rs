@@ -0,0 +1,123 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use dialup::theme::builtin_names;
+use dialup::theme::registry::ThemeRegistry;
+use ratatui::style::Color;
+
+const SEA_DWELLER: &str = include_str!("../themes/sea-dweller.toml");
+
+/// A fresh theme directory holding the given files.
+fn theme_dir(test: &str, files: &[(&str, &str)]) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("dialup-registry-{}-{}", std::process::id(), test));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    for (name, text) in files {
+        fs::write(dir.join(name), text).unwrap();
+    }
+    dir
+}
+
+fn names(registry: &ThemeRegistry) -> Vec<String> {
+    registry.iter().map(|t| t.name().to_string()).collect()
+}
+
+#[test]
+fn lists_builtins_then_files() {
+    let dir = theme_dir("list", &[("deep.toml", SEA_DWELLER)]);
+    let registry = ThemeRegistry::load(&[dir]);
+    assert_eq!(registry.len(), builtin_names().len() + 1);
+    assert_eq!(names(&registry)[0], "Submariner");
+    assert_eq!(names(&registry).last().unwrap(), "Sea-Dweller");
+    assert!(registry.errors().is_empty());
+}
+
+#[test]
+fn finds_themes_by_name_or_file_stem() {
+    let dir = theme_dir("position", &[("deep.toml", SEA_DWELLER)]);
+    let registry = ThemeRegistry::load(&[dir]);
+    let last = registry.len() - 1;
+    assert_eq!(registry.position("Sea-Dweller"), Some(last));
+    assert_eq!(registry.position("sea dweller"), Some(last));
+    assert_eq!(registry.position("deep"), Some(last));
+    assert_eq!(registry.position("GMT-Master"), Some(1));
+    assert_eq!(registry.position("nautilus"), None);
+}
+
+#[test]
+fn resolve_loads_a_path_once() {
+    let dir = theme_dir("resolve", &[]);
+    let path = dir.join("abyss.toml");
+    fs::write(&path, SEA_DWELLER.replace("Sea-Dweller", "Abyss")).unwrap();
+
+    let mut registry = ThemeRegistry::builtin();
+    let index = registry.resolve(path.to_str().unwrap()).unwrap();
+    assert_eq!(registry.get(index).name(), "Abyss");
+    assert_eq!(registry.resolve(path.to_str().unwrap()).unwrap(), index);
+    assert_eq!(registry.resolve("abyss").unwrap(), index);
+
+    let err = registry.resolve("nautilus").unwrap_err();
+    assert!(err.message.contains("unknown theme 'nautilus'"), "{}", err.message);
+    assert!(err.message.contains("Submariner"), "{}", err.message);
+}
+
+#[test]
+fn resolve_puts_a_path_in_place_of_its_namesake() {
+    let submariner = SEA_DWELLER.replace("Sea-Dweller", "Submariner");
+    let searched = theme_dir("namesake-searched", &[("sub.toml", &submariner)]);
+    let named = theme_dir("namesake-named", &[("my-sub.toml", &submariner.replace("#3a3a3a", "red"))]);
+
+    // Neither the built-in nor the file on the search path, but the one asked for
+    let mut registry = ThemeRegistry::load(&[searched]);
+    let index = registry.resolve(named.join("my-sub.toml").to_str().unwrap()).unwrap();
+    assert_eq!(index, 0);
+    assert_eq!(registry.get(index).bezel_color(), Color::Red);
+    assert_eq!(registry.len(), builtin_names().len());
+}
+
+#[test]
+fn files_replace_builtins_and_the_first_file_wins() {
+    let first = theme_dir("precedence-1", &[("sub.toml", &SEA_DWELLER.replace("Sea-Dweller", "Submariner"))]);
+    let second = theme_dir("precedence-2", &[(
+        "sub.toml",
+        &SEA_DWELLER.replace("Sea-Dweller", "Submariner").replace("#3a3a3a", "red"),
+    )]);
+    let registry = ThemeRegistry::load(&[first, second]);
+    assert_eq!(registry.len(), builtin_names().len());
+    // In the built-in's place, from the first directory
+    let theme = registry.get(0);
+    assert_eq!(theme.name(), "Submariner");
+    assert_eq!(theme.bezel_color(), Color::Rgb(0x3a, 0x3a, 0x3a));
+}
+
+#[test]
+fn broken_files_are_kept_as_errors() {
+    let dir = theme_dir("errors", &[
+        ("deep.toml", SEA_DWELLER),
+        ("broken.toml", &SEA_DWELLER.replace("\"light-green\"", "\"lime-ish\"")),
+    ]);
+    let mut registry = ThemeRegistry::load(std::slice::from_ref(&dir));
+    assert_eq!(registry.len(), builtin_names().len() + 1);
+    assert_eq!(registry.errors().len(), 1);
+    let err = &registry.errors()[0];
+    assert_eq!(err.path.as_deref(), Some(dir.join("broken.toml").as_path()));
+    assert_eq!(err.to_string(), format!("{}:11: colors.marker: unknown colour 'lime-ish'", dir.join("broken.toml").display()));
+
+    // Naming the broken file reports why it is missing, as often as it is named
+    for _ in 0..2 {
+        let err = registry.resolve("broken").unwrap_err();
+        assert_eq!(err.path.as_deref().and_then(Path::file_name), Some("broken.toml".as_ref()));
+    }
+    assert_eq!(registry.errors().len(), 1);
+}
+
+#[test]
+fn steps_wrap_round_both_ends() {
+    // As `t`/`T` and the picker move through the themes
+    let registry = ThemeRegistry::builtin();
+    let last = registry.len() - 1;
+    assert_eq!(registry.step(0, 1), 1);
+    assert_eq!(registry.step(last, 1), 0);
+    assert_eq!(registry.step(0, -1), last);
+    assert_eq!(registry.step(2, -(registry.len() as isize)), 2);
+}
@@ -0,0 +1,228 @@
+//! Golden-file tests for the watch face geometry.
+//!
+//! Each case renders the face headlessly at a fixed instant and compares the
+//! result with a file under `tests/golden/`. After an intentional change to
+//! the drawing code, regenerate the files with
+//!
+//! ```text
+//! UPDATE_GOLDEN=1 cargo test --test snapshots
+//! ```
+//!
+//! and review the diff before committing.
+
+use std::fs;
+use std::path::PathBuf;
+
+use chrono::{TimeZone, Utc};
+use dialup::clock::ClockHands;
+use dialup::snapshot;
+use dialup::theme::air_king::AirKingTheme;
+use dialup::theme::cellini::CelliniTheme;
+use dialup::theme::daytona::DaytonaTheme;
+use dialup::theme::gmt_master::GmtMasterTheme;
+use dialup::theme::submariner::SubmarinerTheme;
+use dialup::theme::world_time::WorldTimeTheme;
+use dialup::theme::{HandSet, HandStyle, WatchTheme};
+use ratatui::style::Color;
+use dialup::world_time::{WorldTime, CITIES};
+use dialup::zone::Zone;
+use dialup::{WatchFace, WatchFaceState};
+
+/// 10:08:37.5 on the 9th — hands well apart, two-digit date.
+fn hands(smooth: bool) -> ClockHands {
+    let time = Utc.with_ymd_and_hms(2024, 3, 9, 10, 8, 37).unwrap()
+        + chrono::TimeDelta::milliseconds(500);
+    ClockHands::at(&time, smooth)
+}
+
+fn face(smooth: bool) -> WatchFace<'static> {
+    WatchFace::new(&SubmarinerTheme).hands(hands(smooth))
+}
+
+fn check_golden(name: &str, actual: &str) {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/golden").join(name);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = fs::read_to_string(&path)
+        .unwrap_or_else(|_| panic!("missing golden file {}; run with UPDATE_GOLDEN=1", path.display()));
+    if expected != actual {
+        let line = expected.lines().zip(actual.lines())
+            .position(|(e, a)| e != a)
+            .map_or(String::from("line count differs"), |i| format!("first difference on line {}", i + 1));
+        panic!("{} does not match the rendered face ({line}):\n{actual}", path.display());
+    }
+}
+
+#[test]
+fn quartz() {
+    let buf = snapshot::render(face(false), 80, 40);
+    check_golden("quartz_80x40.txt", &snapshot::to_text(&buf));
+}
+
+#[test]
+fn quartz_ansi() {
+    let buf = snapshot::render(face(false), 80, 40);
+    check_golden("quartz_80x40.ansi", &snapshot::to_ansi(&buf));
+}
+
+#[test]
+fn smooth() {
+    let buf = snapshot::render(face(true), 80, 40);
+    check_golden("smooth_80x40.txt", &snapshot::to_text(&buf));
+}
+
+#[test]
+fn lume() {
+    let buf = snapshot::render(face(false).lume(true), 80, 40);
+    check_golden("lume_80x40.ansi", &snapshot::to_ansi(&buf));
+}
+
+#[test]
+fn stars() {
+    let buf = snapshot::render(face(false).stars(true).elapsed(1.25), 80, 40);
+    check_golden("stars_80x40.ansi", &snapshot::to_ansi(&buf));
+}
+
+#[test]
+fn rotated_bezel() {
+    let bezel = 15.0 / 60.0 * std::f64::consts::TAU;
+    let buf = snapshot::render(face(false).bezel_offset(bezel), 80, 40);
+    check_golden("bezel_80x40.txt", &snapshot::to_text(&buf));
+}
+
+#[test]
+fn wide_terminal() {
+    let buf = snapshot::render(face(false), 140, 30);
+    check_golden("wide_140x30.txt", &snapshot::to_text(&buf));
+}
+
+#[test]
+fn smallest_braille() {
+    let buf = snapshot::render(face(false), 40, 20);
+    check_golden("braille_40x20.txt", &snapshot::to_text(&buf));
+}
+
+#[test]
+fn half_block() {
+    let buf = snapshot::render(face(false), 38, 19);
+    check_golden("halfblock_38x19.ansi", &snapshot::to_ansi(&buf));
+}
+
+#[test]
+fn tiny() {
+    let buf = snapshot::render(face(false), 12, 6);
+    check_golden("tiny_12x6.txt", &snapshot::to_text(&buf));
+}
+
+#[test]
+fn gmt_master() {
+    let mut hands = hands(false);
+    // Second zone five and a half hours ahead
+    hands.gmt_angle += 5.5 * std::f64::consts::TAU / 24.0;
+    let face = WatchFace::new(&GmtMasterTheme::PEPSI).hands(hands);
+    let buf = snapshot::render(face, 80, 40);
+    check_golden("gmt_80x40.ansi", &snapshot::to_ansi(&buf));
+}
+
+#[test]
+fn chronograph() {
+    // 1 h 12 min 41.5 s on the stopwatch
+    let elapsed = std::time::Duration::from_millis(((60 + 12) * 60 + 41) * 1000 + 500);
+    let face = WatchFace::new(&DaytonaTheme).hands(hands(false)).chronograph(elapsed);
+    let buf = snapshot::render(face, 80, 40);
+    check_golden("chronograph_80x40.ansi", &snapshot::to_ansi(&buf));
+}
+
+#[test]
+fn world_time() {
+    let time = Utc.with_ymd_and_hms(2024, 3, 9, 10, 8, 37).unwrap();
+    let world = WorldTime::at(time, Zone::Fixed(chrono::FixedOffset::east_opt(0).unwrap()), CITIES);
+    let face = WatchFace::new(&WorldTimeTheme).hands(hands(false)).world_time(world);
+    let buf = snapshot::render(face, 160, 80);
+    check_golden("world_time_160x80.txt", &snapshot::to_text(&buf));
+}
+
+#[test]
+fn roman_radial_numerals() {
+    let buf = snapshot::render(WatchFace::new(&CelliniTheme).hands(hands(false)), 80, 40);
+    check_golden("cellini_80x40.txt", &snapshot::to_text(&buf));
+}
+
+#[test]
+fn arabic_upright_numerals() {
+    let buf = snapshot::render(WatchFace::new(&AirKingTheme).hands(hands(false)), 80, 40);
+    check_golden("air_king_80x40.txt", &snapshot::to_text(&buf));
+}
+
+/// The Submariner with every hand in one style.
+struct HandTheme(HandStyle);
+
+impl WatchTheme for HandTheme {
+    fn name(&self) -> &str { "hands" }
+    fn bezel_color(&self) -> Color { Color::DarkGray }
+    fn hour_hand_color(&self) -> Color { Color::White }
+    fn minute_hand_color(&self) -> Color { Color::White }
+    fn second_hand_color(&self) -> Color { Color::Red }
+    fn marker_color(&self) -> Color { Color::Green }
+    fn logo_color(&self) -> Color { Color::DarkGray }
+    fn date_color(&self) -> Color { Color::White }
+    fn hand_set(&self) -> HandSet {
+        HandSet { hour: self.0, minute: self.0, second: self.0, gmt: self.0 }
+    }
+}
+
+#[test]
+fn every_hand_style_draws_its_own_shape() {
+    use HandStyle::*;
+    let styles = [
+        Mercedes, Sword, Dauphine, Leaf, Cathedral, Pomme,
+        Syringe, Pencil, Arrow, Skeleton, Lollipop, Needle,
+    ];
+    let rendered: Vec<String> = styles.iter()
+        .map(|&style| {
+            let theme = HandTheme(style);
+            let face = WatchFace::new(&theme).hands(hands(false));
+            snapshot::to_text(&snapshot::render(face, 80, 40))
+        })
+        .collect();
+    for (i, a) in rendered.iter().enumerate() {
+        for (j, b) in rendered.iter().enumerate().skip(i + 1) {
+            assert_ne!(a, b, "{:?} and {:?} draw the same hands", styles[i], styles[j]);
+        }
+    }
+}
+
+#[test]
+fn cached_layers_match_a_fresh_render() {
+    let bezel = 15.0 / 60.0 * std::f64::consts::TAU;
+    let frames = [
+        (face(false), 80, 40),
+        (face(true).stars(true).elapsed(2.0), 80, 40),
+        (face(true).bezel_offset(bezel), 80, 40),
+        (face(true).lume(true), 80, 40),
+        (face(false), 38, 19),
+        (WatchFace::new(&GmtMasterTheme::PEPSI).hands(hands(true)), 80, 40),
+        (face(false).stars(true), 80, 40),
+    ];
+    let mut state = WatchFaceState::default();
+    for (i, (face, w, h)) in frames.into_iter().enumerate() {
+        let fresh = snapshot::render(face.clone(), w, h);
+        let cached = snapshot::render_stateful(face, w, h, &mut state);
+        assert_eq!(snapshot::to_ansi(&cached), snapshot::to_ansi(&fresh), "frame {}", i);
+    }
+}
+
+#[test]
+fn zone_label() {
+    let buf = snapshot::render(face(false).label("New York"), 80, 40);
+    check_golden("label_80x40.txt", &snapshot::to_text(&buf));
+}
+
+#[test]
+fn degenerate_sizes_do_not_panic() {
+    for (w, h) in [(0, 0), (1, 1), (2, 1), (3, 2), (1, 40), (200, 1)] {
+        snapshot::render(face(true).stars(true), w, h);
+    }
+}
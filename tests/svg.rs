@@ -0,0 +1,53 @@
+mod common;
+
+use common::face;
+use dialup::scene::{self, Item, Scene};
+use dialup::svg;
+use ratatui::style::Color;
+
+#[test]
+fn every_item_becomes_one_element() {
+    let scene = face().label("Tokyo & Osaka").scene(&[]);
+    let image = svg::render(&scene, 256);
+
+    assert!(image.starts_with("<svg "));
+    assert!(image.contains(r#"width="256" height="256" viewBox="-100 -100 200 200""#));
+    let elements = ["<line ", "<circle ", "<polygon ", "<path ", "<text "]
+        .iter()
+        .map(|tag| image.matches(tag).count())
+        .sum::<usize>();
+    assert_eq!(elements, scene.items.len());
+    assert!(image.contains(">Tokyo &amp; Osaka</text>"));
+}
+
+#[test]
+fn y_runs_down_the_image() {
+    let mut scene = Scene::default();
+    scene.draw(&ratatui::widgets::canvas::Line { x1: 0.0, y1: 0.0, x2: 0.0, y2: 50.0, color: Color::Red });
+    scene.draw(&dialup::fill::AnnulusSector { inner: 10.0, outer: 20.0, from: 0.0, to: 1.0, color: Color::Blue });
+    let image = svg::render(&scene, 100);
+
+    assert!(image.contains(r##"<line x1="0" y1="0" x2="0" y2="-50" stroke="#cd0000"/>"##));
+    // Starts out from 12 o'clock, at the top of the image
+    assert!(image.contains(r##"<path d="M0,-10 L0,-20 A20,20 0 0 1 "##));
+}
+
+#[test]
+fn lume_scene_keeps_only_the_luminous_parts() {
+    let lit = face().lume(true).scene(&[]);
+    let colors: Vec<Color> = lit.items.iter()
+        .map(|item| match item {
+            Item::Line { color, .. } | Item::Circle { color, .. } | Item::Polygon { color, .. }
+            | Item::Disc { color, .. } | Item::AnnulusSector { color, .. } | Item::Label { color, .. } => *color,
+        })
+        .collect();
+    assert!(colors.len() < face().scene(&[]).items.len());
+    assert!(colors.iter().all(|&c| c == Color::LightGreen || c == Color::Red), "{:?}", colors);
+}
+
+#[test]
+fn indexed_colours_follow_the_xterm_palette() {
+    assert_eq!(scene::rgb(Color::Indexed(9)), scene::rgb(Color::LightRed));
+    assert_eq!(scene::rgb(Color::Indexed(196)), (0xff, 0x00, 0x00));
+    assert_eq!(scene::rgb(Color::Indexed(244)), (0x80, 0x80, 0x80));
+}
@@ -0,0 +1,199 @@
+use std::f64::consts::TAU;
+use std::path::PathBuf;
+
+use chrono::{TimeZone, Utc};
+use dialup::clock::ClockHands;
+use dialup::snapshot;
+use dialup::theme::file::{FileTheme, ThemeError};
+use dialup::theme::{BezelAction, BezelSpec, BezelStyle, HandSet, HandStyle, HourIndex, Orientation, WatchTheme};
+use dialup::WatchFace;
+use ratatui::style::Color;
+
+const HANDS: Color = Color::Rgb(0xf5, 0xf5, 0xf0);
+
+/// The example theme with `top` put before its first line, `tables` after
+/// its last, and each `(from, to)` edit made.
+fn source(top: &str, tables: &str, edits: &[(&str, &str)]) -> String {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("themes/sea-dweller.toml");
+    let mut text = std::fs::read_to_string(path).unwrap();
+    for (from, to) in edits {
+        assert!(text.contains(from), "the example has no {:?}", from);
+        text = text.replace(from, to);
+    }
+    format!("{}{}\n{}", top, text, tables)
+}
+
+fn parse(top: &str, tables: &str, edits: &[(&str, &str)]) -> FileTheme {
+    FileTheme::parse(&source(top, tables, edits), "fallback").unwrap()
+}
+
+fn error(top: &str, tables: &str, edits: &[(&str, &str)]) -> ThemeError {
+    FileTheme::parse(&source(top, tables, edits), "fallback").unwrap_err()
+}
+
+/// The face for `theme` at 10:08:37 on the 9th.
+fn face(theme: &FileTheme) -> WatchFace<'_> {
+    let time = Utc.with_ymd_and_hms(2024, 3, 9, 10, 8, 37).unwrap();
+    WatchFace::new(theme).hands(ClockHands::at(&time, false))
+}
+
+/// The cells drawn in `color` when `face` is rendered at 80 × 40.
+fn cells(face: WatchFace, color: Color) -> Vec<(u16, u16)> {
+    let buf = snapshot::render(face, 80, 40);
+    buf.area.positions().filter(|&p| buf[p].fg == color).map(|p| (p.x, p.y)).collect()
+}
+
+#[test]
+fn example_theme_draws_in_its_colours() {
+    let theme = parse("", "", &[]);
+    assert_eq!(theme.name(), "Sea-Dweller");
+    assert!(theme.has_date_window());
+    let parts = [
+        ("bezel", Color::Rgb(0x3a, 0x3a, 0x3a)),
+        ("markers", Color::LightGreen),
+        ("hands", HANDS),
+        ("second hand", Color::Rgb(235, 235, 225)),
+        ("logo", Color::Rgb(0x8a, 0x1c, 0x1c)),
+    ];
+    for (part, color) in parts {
+        assert!(!cells(face(&theme), color).is_empty(), "no {} drawn in {:?}", part, color);
+    }
+}
+
+#[test]
+fn hand_lengths_are_fractions_of_the_dial() {
+    let long = parse("", "", &[]);
+    let short = parse("", "", &[("minute = 0.95", "minute = 0.6")]);
+    assert_eq!(short.minute_hand_length(), 0.6);
+    assert!(cells(face(&short), HANDS).len() < cells(face(&long), HANDS).len());
+}
+
+#[test]
+fn name_defaults_to_fallback() {
+    assert_eq!(parse("", "", &[("name = \"Sea-Dweller\"\n", "")]).name(), "fallback");
+}
+
+#[test]
+fn errors_name_the_line_and_field() {
+    let err = error("", "", &[("\"light-green\"", "\"lime-ish\"")]);
+    assert_eq!((err.line, err.field.as_deref()), (Some(11), Some("colors.marker")));
+    assert!(err.message.contains("lime-ish"), "{}", err.message);
+
+    let err = error("", "", &[("minute = 0.95", "minute = 4.0")]);
+    assert_eq!((err.line, err.field.as_deref()), (Some(17), Some("hands.minute")));
+
+    let err = error("", "", &[("date = \"white\"\n", "")]);
+    assert!(err.message.contains("date"), "{}", err.message);
+}
+
+#[test]
+fn gmt_theme_draws_a_two_tone_bezel_and_a_gmt_hand() {
+    let tables = "[bezel]\nstyle = \"gmt\"\nday = \"red\"\nnight = \"blue\"\n";
+    let theme = parse("gmt-hand = true\n", tables, &[]);
+    assert_eq!(theme.bezel_style(), BezelStyle::Gmt24 { day: Color::Red, night: Color::Blue });
+    assert!(!cells(face(&theme), Color::Red).is_empty());
+    assert!(!cells(face(&theme), Color::Blue).is_empty());
+
+    // The GMT hand is drawn in the second-hand colour unless given its own
+    let plain = parse("", tables, &[]);
+    assert!(!plain.has_gmt_hand());
+    let second = theme.second_hand_color();
+    assert!(cells(face(&theme), second).len() > cells(face(&plain), second).len());
+}
+
+#[test]
+fn bezel_defaults_to_dive() {
+    let theme = parse("", "", &[]);
+    assert_eq!(theme.bezel_style(), BezelStyle::Dive);
+    assert_eq!(theme.bezel(), BezelSpec { clicks: 120, action: BezelAction::Unidirectional });
+    assert!(cells(face(&theme), Color::Red).is_empty());
+}
+
+#[test]
+fn bezel_mechanism_sets_the_detents() {
+    let theme = parse("", "[bezel]\nstyle = \"gmt\"\nclicks = 48\naction = \"bidirectional\"\n", &[]);
+    let spec = theme.bezel();
+    assert_eq!(spec, BezelSpec { clicks: 48, action: BezelAction::Bidirectional });
+    // 48 clicks are 7.5° apart, and a key press turns one of them
+    assert!((spec.snap(0.14) - 7.5f64.to_radians()).abs() < 1e-9);
+    assert_eq!(spec.coarse_step(), 1);
+
+    let friction = parse("", "[bezel]\naction = \"friction-lock\"\n", &[]).bezel();
+    assert_eq!(friction.snap(0.14), 0.14);
+
+    let err = error("", "[bezel]\nclicks = 1\n", &[]);
+    assert_eq!(err.field.as_deref(), Some("bezel.clicks"));
+}
+
+#[test]
+fn the_bezel_turns_by_whole_clicks() {
+    let theme = parse("", "[bezel]\nclicks = 12\n", &[]);
+    let spec = theme.bezel();
+    let turned = |angle: f64| snapshot::to_text(&snapshot::render(face(&theme).bezel_offset(spec.snap(angle)), 80, 40));
+    assert_eq!(turned(0.1), turned(0.0));
+    assert_ne!(turned(0.4), turned(0.0));
+    assert_eq!(turned(0.4), turned(TAU / 12.0));
+}
+
+#[test]
+fn logo_text_is_drawn_in_the_stroke_font() {
+    let logo = |theme: &FileTheme| cells(face(theme), theme.logo_color());
+    let default = parse("", "", &[]);
+    assert_eq!(default.logo_text(), "RUSTLEX");
+
+    let short = parse("logo-text = \"I\"\n", "", &[]);
+    assert!(!logo(&short).is_empty() && logo(&short).len() < logo(&default).len());
+    let long = parse("logo-text = \"Sea-Dweller 4000\"\n", "", &[]);
+    assert_eq!(long.logo_text(), "Sea-Dweller 4000");
+    assert_ne!(logo(&long), logo(&default));
+
+    let err = error("logo-text = \"SÜBMARINER\"\n", "", &[]);
+    assert_eq!((err.line, err.field.as_deref()), (Some(1), Some("logo-text")));
+    assert!(err.message.contains("'Ü'"), "{}", err.message);
+}
+
+#[test]
+fn hour_index_styles_draw_their_own_markers() {
+    let dial = |table: &str| parse("", &format!("[dial]\n{}\n", table), &[]);
+    let dots = parse("", "", &[]);
+    assert_eq!(dots.hour_index(), HourIndex::DotsAndBatons);
+    let roman = dial("index = \"roman\"\nnumerals = \"radial\"\nfour = \"iv\"");
+    assert_eq!(roman.hour_index(), HourIndex::Roman { orientation: Orientation::Radial, iiii: false });
+    let iiii = dial("index = \"roman\"\nnumerals = \"radial\"");
+    let breguet = dial("index = \"breguet\"");
+    assert_eq!(breguet.hour_index(), HourIndex::Breguet(Orientation::Upright));
+
+    // Each style, and IIII against IV, marks the hours differently
+    let drawn = [&dots, &roman, &iiii, &breguet].map(|theme| cells(face(theme), Color::LightGreen));
+    for (i, a) in drawn.iter().enumerate() {
+        for b in &drawn[i + 1..] {
+            assert_ne!(a, b);
+        }
+    }
+
+    let err = error("", "[dial]\nindex = \"clous-de-paris\"\n", &[]);
+    assert_eq!(err.field.as_deref(), Some("dial.index"));
+}
+
+#[test]
+fn hand_styles_change_the_hands() {
+    let styles = |hour: &str, minute: &str| {
+        let keys = format!("second = 0.95\nhour-style = \"{}\"\nminute-style = \"{}\"\n", hour, minute);
+        parse("", "", &[("second = 0.95\n", &keys)])
+    };
+    let sport = parse("", "", &[]);
+    assert_eq!(sport.hand_set(), HandSet::SPORT);
+    let pomme = styles("pomme", "pomme");
+    assert_eq!(pomme.hand_set().hour, HandStyle::Pomme);
+    assert_eq!(pomme.hand_set().minute, HandStyle::Pomme);
+    assert_eq!(pomme.hand_set().second, HandStyle::Lollipop);
+    let dauphine = styles("dauphine", "dauphine");
+
+    let drawn = [&sport, &pomme, &dauphine].map(|theme| cells(face(theme), HANDS));
+    assert_ne!(drawn[0], drawn[1]);
+    assert_ne!(drawn[1], drawn[2]);
+    assert_ne!(drawn[0], drawn[2]);
+
+    let err = error("", "", &[("second = 0.95\n", "second = 0.95\nhour-style = \"spade\"\n")]);
+    assert_eq!(err.field.as_deref(), Some("hands.hour-style"));
+}
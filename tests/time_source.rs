@@ -0,0 +1,75 @@
+use std::time::Duration;
+
+use chrono::{DateTime, TimeDelta, TimeZone, Utc};
+use dialup::time_source::{
+    until_next_second, AcceleratedClock, FixedClock, OffsetClock, ScriptedClock, SystemClock, TimeSource,
+};
+
+#[test]
+fn quartz_wakes_on_the_next_whole_second() {
+    let clock = SystemClock;
+    let wait = until_next_second(&clock).unwrap();
+    assert!(wait > Duration::ZERO && wait <= Duration::from_secs(1));
+    // After the wait the wall clock is on a new second
+    let before = clock.now().timestamp();
+    std::thread::sleep(wait + Duration::from_millis(2));
+    assert_eq!(clock.now().timestamp(), before + 1);
+}
+
+#[test]
+fn fast_clocks_tick_sooner_and_fixed_ones_never() {
+    let noon = Utc.with_ymd_and_hms(2024, 3, 9, 12, 0, 0).unwrap();
+    // Sixty times real time: a second passes every 1/60 s at most
+    let fast = AcceleratedClock::new(noon, 60.0);
+    assert!(until_next_second(&fast).unwrap() <= Duration::from_secs(1) / 60);
+
+    let fixed = FixedClock(noon + TimeDelta::milliseconds(250));
+    assert_eq!(until_next_second(&fixed), None);
+    // Shifting a source keeps its rate
+    assert_eq!(until_next_second(&OffsetClock::new(fixed, TimeDelta::hours(1))), None);
+}
+
+#[test]
+fn offset_shifts_the_inner_source() {
+    let noon = Utc.with_ymd_and_hms(2024, 3, 9, 12, 0, 0).unwrap();
+    let clock = OffsetClock::new(FixedClock(noon), TimeDelta::minutes(-90));
+    assert_eq!(clock.now(), Utc.with_ymd_and_hms(2024, 3, 9, 10, 30, 0).unwrap());
+    assert_eq!(OffsetClock::new(SystemClock, TimeDelta::hours(5)).rate(), 1.0);
+}
+
+#[test]
+fn accelerated_runs_at_its_rate_from_the_origin() {
+    let noon = Utc.with_ymd_and_hms(2024, 3, 9, 12, 0, 0).unwrap();
+    let clock = AcceleratedClock::new(noon, 3600.0);
+    assert_eq!(clock.rate(), 3600.0);
+    let start = clock.now();
+    assert!(start >= noon && start - noon < TimeDelta::seconds(60));
+    // 20 ms of real time is at least 72 simulated seconds
+    std::thread::sleep(Duration::from_millis(20));
+    let passed = clock.now() - start;
+    assert!(passed >= TimeDelta::seconds(72), "{}", passed);
+}
+
+#[test]
+fn accelerated_stops_at_the_ends_of_the_calendar() {
+    let noon = Utc.with_ymd_and_hms(2024, 3, 9, 12, 0, 0).unwrap();
+    let forward = AcceleratedClock::new(noon, 1e300);
+    let back = AcceleratedClock::new(noon, -1e300);
+    std::thread::sleep(Duration::from_millis(1));
+    assert_eq!(forward.now(), DateTime::<Utc>::MAX_UTC);
+    assert_eq!(back.now(), DateTime::<Utc>::MIN_UTC);
+}
+
+#[test]
+fn scripted_steps_through_then_holds_the_last_instant() {
+    let noon = Utc.with_ymd_and_hms(2024, 3, 9, 12, 0, 0).unwrap();
+    let clock = ScriptedClock::stepped(noon, TimeDelta::seconds(15), 3);
+    let seen: Vec<_> = (0..5).map(|_| clock.now()).collect();
+    let at = |secs| noon + TimeDelta::seconds(secs);
+    assert_eq!(seen, [at(0), at(15), at(30), at(30), at(30)]);
+    assert_eq!(clock.rate(), 1.0);
+
+    // A count of zero still gives one instant
+    let clock = ScriptedClock::stepped(noon, TimeDelta::seconds(15), 0);
+    assert_eq!((clock.now(), clock.now()), (noon, noon));
+}
@@ -0,0 +1,38 @@
+use std::f64::consts::TAU;
+
+use chrono::{TimeZone, Utc};
+use dialup::world_time::{WorldTime, CITIES};
+use dialup::zone::Zone;
+
+fn angle_of(world: &WorldTime, name: &str) -> f64 {
+    world.cities.iter().find(|(city, _)| *city == name).unwrap().1 / TAU * 24.0
+}
+
+#[test]
+fn home_sits_at_twelve_and_cities_follow_their_offsets() {
+    let london: Zone = "Europe/London".parse().unwrap();
+    let noon = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+    let world = WorldTime::at(noon, london, CITIES);
+
+    assert!(angle_of(&world, "LONDON").abs() < 1e-9);
+    // Tokyo is nine hours ahead, so it sits nine hours counter-clockwise
+    assert!((angle_of(&world, "TOKYO") - 15.0).abs() < 1e-9);
+    assert!((angle_of(&world, "NEW YORK") - 5.0).abs() < 1e-9);
+    // 12:00 on the 24-hour ring is at the top
+    assert!(world.hour_angle(12.0).rem_euclid(TAU) < 1e-9);
+}
+
+#[test]
+fn daylight_saving_moves_cities() {
+    let london: Zone = "Europe/London".parse().unwrap();
+    let summer = Utc.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap();
+    let world = WorldTime::at(summer, london, CITIES);
+
+    // BST against JST: eight hours apart; EDT against BST: still five
+    assert!((angle_of(&world, "TOKYO") - 16.0).abs() < 1e-9);
+    assert!((angle_of(&world, "NEW YORK") - 5.0).abs() < 1e-9);
+    // Sydney is on standard time in July
+    assert!((angle_of(&world, "SYDNEY") - 15.0).abs() < 1e-9);
+    // 13:00 BST at the top of the ring
+    assert!(world.hour_angle(13.0).rem_euclid(TAU) < 1e-9);
+}
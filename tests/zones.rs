@@ -0,0 +1,37 @@
+use chrono::{TimeZone, Utc};
+use dialup::zone::Zone;
+
+#[test]
+fn parses_iana_names_case_insensitively() {
+    let zone: Zone = "america/new_york".parse().unwrap();
+    assert_eq!(zone.to_string(), "America/New_York");
+    assert_eq!(zone.label(), "New York");
+    assert!("Mars/Olympus_Mons".parse::<Zone>().is_err());
+}
+
+#[test]
+fn offsets_and_local_still_parse() {
+    assert_eq!("local".parse::<Zone>().unwrap(), Zone::Local);
+    assert_eq!("UTC+05:30".parse::<Zone>().unwrap().to_string(), "UTC+05:30");
+    assert_eq!("UTC".parse::<Zone>().unwrap().label(), "UTC");
+}
+
+#[test]
+fn named_zones_follow_daylight_saving() {
+    let london: Zone = "Europe/London".parse().unwrap();
+    let winter = Utc.with_ymd_and_hms(2024, 1, 15, 12, 0, 0).unwrap();
+    let summer = Utc.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap();
+    let hour = |zone: Zone, t| zone.hands(t, false).hour_angle / std::f64::consts::TAU * 12.0;
+    assert!((hour(london, winter) - 0.0).abs() < 1e-9); // 12:00 GMT
+    assert!((hour(london, summer) - 1.0).abs() < 1e-9); // 13:00 BST
+}
+
+#[test]
+fn wall_times_resolve_across_daylight_saving() {
+    let london: Zone = "Europe/London".parse().unwrap();
+    let wall = |s| chrono::NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M").unwrap();
+    assert_eq!(london.instant_at(wall("2024-07-15 13:00")), Some(Utc.with_ymd_and_hms(2024, 7, 15, 12, 0, 0).unwrap()));
+    // 01:30 is skipped when the clocks go forward and repeated when they go back
+    assert_eq!(london.instant_at(wall("2024-03-31 01:30")), None);
+    assert_eq!(london.instant_at(wall("2024-10-27 01:30")), Some(Utc.with_ymd_and_hms(2024, 10, 27, 0, 30, 0).unwrap()));
+}